@@ -0,0 +1,237 @@
+use shared::{option_value, Format, InputSource, MyError};
+use std::str::FromStr;
+
+pub const USAGE: &str = "Usage: aoc run <day> [--part 1|2] [--input path|-] [--input-str text] [--format text|json]
+       aoc verify [--answers path]
+       aoc bench [<day>] [--part 1|2] [--input path|-] [--input-str text] [--runs n] [--format text|json]
+
+Without --input, inputs are read from inputs/<year>/<day>.txt (AOC_INPUT_DIR, AOC_YEAR)
+and downloaded there first when AOC_URL and AOC_SESSION are set, otherwise from
+the day's input.txt.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        day: u8,
+        part: Option<u8>,
+        input: Option<InputSource>,
+        format: Format,
+    },
+    Verify {
+        answers: Option<String>,
+    },
+    Bench {
+        day: Option<u8>,
+        part: Option<u8>,
+        input: Option<InputSource>,
+        runs: usize,
+        format: Format,
+    },
+}
+
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, MyError> {
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("verify") => parse_verify(args),
+        Some("bench") => parse_bench(args.peekable()),
+        Some(other) => Err(invalid(&format!("Unknown command '{}'", other))),
+        None => Err(invalid("Command must be provided")),
+    }
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<Command, MyError> {
+    let day = args
+        .next()
+        .ok_or_else(|| invalid("Day must be provided"))
+        .and_then(|day| parse_number(&day, "day"))?;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::Text;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = Some(parse_part(&next_value(&mut args, "--part")?)?),
+            "--input" => input = Some(InputSource::from_arg(&next_value(&mut args, "--input")?)),
+            "--input-str" => input = Some(InputSource::Str(next_value(&mut args, "--input-str")?)),
+            "--format" => format = parse_format(&next_value(&mut args, "--format")?)?,
+            other => return Err(invalid(&format!("Unknown option '{}'", other))),
+        }
+    }
+
+    Ok(Command::Run {
+        day,
+        part,
+        input,
+        format,
+    })
+}
+
+fn parse_verify<I: Iterator<Item = String>>(mut args: I) -> Result<Command, MyError> {
+    let mut answers = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => answers = Some(next_value(&mut args, "--answers")?),
+            other => return Err(invalid(&format!("Unknown option '{}'", other))),
+        }
+    }
+
+    Ok(Command::Verify { answers })
+}
+
+fn parse_bench<I: Iterator<Item = String>>(
+    mut args: std::iter::Peekable<I>,
+) -> Result<Command, MyError> {
+    let day = match args.next_if(|arg| !arg.starts_with("--")) {
+        Some(day) => Some(parse_number(&day, "day")?),
+        None => None,
+    };
+    let mut part = None;
+    let mut input = None;
+    let mut runs = 10;
+    let mut format = Format::Text;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = Some(parse_part(&next_value(&mut args, "--part")?)?),
+            "--input" => input = Some(InputSource::from_arg(&next_value(&mut args, "--input")?)),
+            "--input-str" => input = Some(InputSource::Str(next_value(&mut args, "--input-str")?)),
+            "--runs" => match parse_number(&next_value(&mut args, "--runs")?, "runs")? {
+                0 => return Err(invalid("Runs must be at least 1")),
+                n => runs = n,
+            },
+            "--format" => format = parse_format(&next_value(&mut args, "--format")?)?,
+            other => return Err(invalid(&format!("Unknown option '{}'", other))),
+        }
+    }
+    if day.is_none() && input.is_some() {
+        return Err(invalid("Options --input and --input-str require a day"));
+    }
+
+    Ok(Command::Bench {
+        day,
+        part,
+        input,
+        runs,
+        format,
+    })
+}
+
+/// `shared::option_value` with the usage appended to the error.
+fn next_value<I: Iterator<Item = String>>(args: &mut I, option: &str) -> Result<String, MyError> {
+    option_value(args, option).map_err(|err| invalid(&err.to_string()))
+}
+
+fn parse_part(value: &str) -> Result<u8, MyError> {
+    match parse_number(value, "part")? {
+        part @ (1 | 2) => Ok(part),
+        _ => Err(invalid("Part must be 1 or 2")),
+    }
+}
+
+fn parse_format(value: &str) -> Result<Format, MyError> {
+    value
+        .parse()
+        .map_err(|err: MyError| invalid(&err.to_string()))
+}
+
+fn parse_number<T: FromStr>(value: &str, name: &str) -> Result<T, MyError> {
+    value
+        .parse()
+        .map_err(|_| invalid(&format!("Invalid {} '{}'", name, value)))
+}
+
+fn invalid(message: &str) -> MyError {
+    MyError::InvalidArgument(format!("{}\n{}", message, USAGE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let expected = Command::Run {
+            day: 3,
+            part: None,
+            input: None,
+            format: Format::Text,
+        };
+        assert_eq!(expected, parse_args(args("run 3")).unwrap());
+
+        let expected = Command::Run {
+            day: 12,
+            part: Some(2),
+            input: Some(InputSource::File("test.txt".to_string())),
+            format: Format::Json,
+        };
+        let actual = parse_args(args("run 12 --input test.txt --part 2 --format json")).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_run_invalid() {
+        assert!(parse_args(args("run")).is_err());
+        assert!(parse_args(args("run x")).is_err());
+        assert!(parse_args(args("run 3 --part 3")).is_err());
+        assert!(parse_args(args("run 3 --input")).is_err());
+        assert!(parse_args(args("run 3 --format csv")).is_err());
+        assert!(parse_args(args("run 3 --input-str")).is_err());
+        assert!(parse_args(args("walk 3")).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let expected = Command::Verify { answers: None };
+        assert_eq!(expected, parse_args(args("verify")).unwrap());
+
+        let expected = Command::Verify {
+            answers: Some("examples.txt".to_string()),
+        };
+        let actual = parse_args(args("verify --answers examples.txt")).unwrap();
+        assert_eq!(expected, actual);
+
+        assert!(parse_args(args("verify --answers")).is_err());
+        assert!(parse_args(args("verify 3")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let expected = Command::Bench {
+            day: None,
+            part: None,
+            input: None,
+            runs: 10,
+            format: Format::Text,
+        };
+        assert_eq!(expected, parse_args(args("bench")).unwrap());
+
+        let expected = Command::Bench {
+            day: Some(6),
+            part: Some(1),
+            input: None,
+            runs: 100,
+            format: Format::Json,
+        };
+        let actual = parse_args(args("bench 6 --runs 100 --part 1 --format json")).unwrap();
+        assert_eq!(expected, actual);
+
+        let expected = Command::Bench {
+            day: Some(1),
+            part: None,
+            input: Some(InputSource::Stdin),
+            runs: 10,
+            format: Format::Text,
+        };
+        assert_eq!(expected, parse_args(args("bench 1 --input -")).unwrap());
+
+        assert!(parse_args(args("bench --runs 0")).is_err());
+        assert!(parse_args(args("bench --format xml")).is_err());
+        assert!(parse_args(args("bench --input test.txt")).is_err());
+        assert!(parse_args(args("bench --input-str 199")).is_err());
+    }
+}
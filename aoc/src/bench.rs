@@ -0,0 +1,56 @@
+use crate::days::{self, Day};
+use shared::{Format, InputSource, MyError, Timing};
+
+/// Times the given day, or every day when none is given, on its input.
+pub fn bench(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<InputSource>,
+    runs: usize,
+    format: Format,
+) -> Result<(), MyError> {
+    let selected: Vec<&Day> = match day {
+        Some(day) => vec![days::find(day)?],
+        None => days::DAYS.iter().collect(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut timings = vec![];
+    for day in selected {
+        let input = match &input {
+            Some(source) => source.read()?,
+            None => day.default_input()?,
+        };
+        timings.append(&mut (day.bench)(&input, &parts, runs)?);
+    }
+
+    match format {
+        Format::Text => print_table(&timings),
+        Format::Json => {
+            let json = timings.iter().map(Timing::to_json).collect::<Vec<_>>();
+            println!("{}", serde_json::Value::from(json));
+        }
+    }
+    Ok(())
+}
+
+fn print_table(timings: &[Timing]) {
+    println!(
+        "{:>3}  {:<6} {:>5} {:>12} {:>12} {:>12}",
+        "Day", "Stage", "Runs", "Min", "Median", "Max"
+    );
+    for timing in timings {
+        println!(
+            "{:>3}  {:<6} {:>5} {:>12.1?} {:>12.1?} {:>12.1?}",
+            timing.day,
+            timing.stage.to_string(),
+            timing.samples.len(),
+            timing.min(),
+            timing.median(),
+            timing.max()
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use shared::{
+    solve, time_solution, InputCache, InputSource, MyError, PartResult, PuzzleInput, Timing,
+};
+use std::path::Path;
+
+type SolveFn = fn(&PuzzleInput, &[u8]) -> Result<Vec<PartResult>, MyError>;
+type BenchFn = fn(&PuzzleInput, &[u8], usize) -> Result<Vec<Timing>, MyError>;
+
+pub struct Day {
+    pub number: u8,
+    pub name: &'static str,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+}
+
+impl Day {
+    /// The day's input from the cache under `inputs/` (see
+    /// `InputCache::from_env`), falling back to the crate's own `input.txt`.
+    pub fn default_input(&self) -> Result<PuzzleInput, MyError> {
+        let cache = InputCache::from_env(workspace_path(Path::new("inputs")))?;
+        match cache.find(self.number)? {
+            Some(input) => Ok(input),
+            None => {
+                InputSource::File(workspace_path(&Path::new(self.name).join("input.txt"))).read()
+            }
+        }
+    }
+}
+
+pub fn default_answers() -> String {
+    workspace_path(Path::new("answers.txt"))
+}
+
+fn workspace_path(path: &Path) -> String {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(path)
+        .to_string_lossy()
+        .into_owned()
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        name: "day01-sonar-sweep",
+        solve: solve::<day01_sonar_sweep::SonarSweep>,
+        bench: time_solution::<day01_sonar_sweep::SonarSweep>,
+    },
+    Day {
+        number: 2,
+        name: "day02-dive",
+        solve: solve::<day02_dive::Dive>,
+        bench: time_solution::<day02_dive::Dive>,
+    },
+    Day {
+        number: 3,
+        name: "day03-binary-diagnostic",
+        solve: solve::<day03_binary_diagnostic::BinaryDiagnostic>,
+        bench: time_solution::<day03_binary_diagnostic::BinaryDiagnostic>,
+    },
+    Day {
+        number: 4,
+        name: "day04-giant-squid",
+        solve: solve::<day04_giant_squid::GiantSquid>,
+        bench: time_solution::<day04_giant_squid::GiantSquid>,
+    },
+    Day {
+        number: 5,
+        name: "day05-hydrothermal-venture",
+        solve: solve::<day05_hydrothermal_venture::HydrothermalVenture>,
+        bench: time_solution::<day05_hydrothermal_venture::HydrothermalVenture>,
+    },
+    Day {
+        number: 6,
+        name: "day06-lanternfish",
+        solve: solve::<day06_lanternfish::Lanternfish>,
+        bench: time_solution::<day06_lanternfish::Lanternfish>,
+    },
+    Day {
+        number: 7,
+        name: "day07-the-treachery-of-whales",
+        solve: solve::<day07_the_treachery_of_whales::TreacheryOfWhales>,
+        bench: time_solution::<day07_the_treachery_of_whales::TreacheryOfWhales>,
+    },
+    Day {
+        number: 8,
+        name: "day08-seven-segment-search",
+        solve: solve::<day08_seven_segment_search::SevenSegmentSearch>,
+        bench: time_solution::<day08_seven_segment_search::SevenSegmentSearch>,
+    },
+    Day {
+        number: 9,
+        name: "day09-smoke-basin",
+        solve: solve::<day09_smoke_basin::SmokeBasin>,
+        bench: time_solution::<day09_smoke_basin::SmokeBasin>,
+    },
+    Day {
+        number: 10,
+        name: "day10-syntax-scoring",
+        solve: solve::<day10_syntax_scoring::SyntaxScoring>,
+        bench: time_solution::<day10_syntax_scoring::SyntaxScoring>,
+    },
+    Day {
+        number: 11,
+        name: "day11-dumbo-octopus",
+        solve: solve::<day11_dumbo_octopus::DumboOctopus>,
+        bench: time_solution::<day11_dumbo_octopus::DumboOctopus>,
+    },
+    Day {
+        number: 12,
+        name: "day12-passage-pathing",
+        solve: solve::<day12_passage_pathing::PassagePathing>,
+        bench: time_solution::<day12_passage_pathing::PassagePathing>,
+    },
+    Day {
+        number: 13,
+        name: "day13-transparent-origami",
+        solve: solve::<day13_transparent_origami::TransparentOrigami>,
+        bench: time_solution::<day13_transparent_origami::TransparentOrigami>,
+    },
+    Day {
+        number: 14,
+        name: "day14-extended-polymerisation",
+        solve: solve::<day14_extended_polymerisation::ExtendedPolymerisation>,
+        bench: time_solution::<day14_extended_polymerisation::ExtendedPolymerisation>,
+    },
+    Day {
+        number: 15,
+        name: "day15-chiton",
+        solve: solve::<day15_chiton::Chiton>,
+        bench: time_solution::<day15_chiton::Chiton>,
+    },
+];
+
+pub fn find(number: u8) -> Result<&'static Day, MyError> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| MyError::InvalidArgument(format!("Day {} is not solved", number)))
+}
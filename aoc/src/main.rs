@@ -0,0 +1,47 @@
+mod args;
+mod bench;
+mod days;
+mod verify;
+
+use args::Command;
+use shared::{print_results, Format, InputSource, MyError};
+
+fn main() -> Result<(), MyError> {
+    match args::parse_args(std::env::args().skip(1))? {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input, format),
+        Command::Verify { answers } => {
+            verify::verify(&answers.unwrap_or_else(days::default_answers))
+        }
+        Command::Bench {
+            day,
+            part,
+            input,
+            runs,
+            format,
+        } => bench::bench(day, part, input, runs, format),
+    }
+}
+
+fn run(
+    day: u8,
+    part: Option<u8>,
+    input: Option<InputSource>,
+    format: Format,
+) -> Result<(), MyError> {
+    let day = days::find(day)?;
+    let input = match input {
+        Some(source) => source.read()?,
+        None => day.default_input()?,
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    print_results((day.solve)(&input, &parts)?, format)
+}
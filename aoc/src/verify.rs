@@ -0,0 +1,67 @@
+use crate::days;
+use shared::answers::{diff, parse_answers, ExpectedAnswer};
+use shared::{MyError, PuzzleInput};
+use std::path::Path;
+
+/// Runs every day listed in the answers file and compares the results,
+/// printing a diff for each answer that does not match.
+pub fn verify(answers_path: &str) -> Result<(), MyError> {
+    let expected = parse_answers(&PuzzleInput::from_file(answers_path)?)?;
+    let base = Path::new(answers_path)
+        .parent()
+        .unwrap_or_else(|| Path::new(""));
+
+    let mut failed = 0;
+    for (day, input, answers) in group_by_run(&expected) {
+        let input_path = base.join(input).to_string_lossy().into_owned();
+        let parts = answers.iter().map(|a| a.part).collect::<Vec<_>>();
+        let results =
+            days::find(day).and_then(|d| (d.solve)(&PuzzleInput::from_file(&input_path)?, &parts));
+
+        for (i, expected) in answers.iter().enumerate() {
+            let actual = match &results {
+                Ok(results) => results[i]
+                    .answer
+                    .as_ref()
+                    .map_err(|err| err.to_string())
+                    .map(String::as_str),
+                Err(err) => Err(err.to_string()),
+            };
+            let label = format!("day {} part {} ({})", day, expected.part, input);
+
+            match actual {
+                Ok(actual) if actual == expected.answer => println!("PASS {}", label),
+                Ok(actual) => {
+                    failed += 1;
+                    println!("FAIL {}\n{}", label, diff(&expected.answer, actual));
+                }
+                Err(err) => {
+                    failed += 1;
+                    println!("FAIL {}\n{}", label, err);
+                }
+            }
+        }
+    }
+
+    let total = expected.len();
+    println!("{} of {} answers match", total - failed, total);
+    if failed > 0 {
+        return Err(MyError::VerifyFailed { failed, total });
+    }
+    Ok(())
+}
+
+/// Groups answers by day and input, so every input is parsed only once.
+fn group_by_run(answers: &[ExpectedAnswer]) -> Vec<(u8, &str, Vec<&ExpectedAnswer>)> {
+    let mut groups: Vec<(u8, &str, Vec<&ExpectedAnswer>)> = vec![];
+    for answer in answers {
+        match groups
+            .iter_mut()
+            .find(|(day, input, _)| *day == answer.day && *input == answer.input)
+        {
+            Some((_, _, group)) => group.push(answer),
+            None => groups.push((answer.day, &answer.input, vec![answer])),
+        }
+    }
+    groups
+}
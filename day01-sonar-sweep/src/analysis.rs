@@ -0,0 +1,217 @@
+use crate::{Comparison, SonarSweep, StreamAnalyser, WindowStats};
+use serde_json::{json, Value};
+use shared::{option_value, InputSource, MyError, PartResult, Solution};
+use std::fmt;
+
+/// Analysis of a sonar trace requested on the command line instead of the
+/// two puzzle answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Window sizes to compare, the two puzzle parts' if none are given.
+    pub windows: Vec<usize>,
+    pub comparison: Comparison,
+    pub longest_run: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            windows: vec![],
+            comparison: Comparison::Increases,
+            longest_run: false,
+        }
+    }
+}
+
+/// Takes `--stream`, `--window n` (repeatable), `--compare
+/// increases|decreases|plateaus` and `--longest-run` out of `args`. The
+/// options are `None` if none of them were given; the other arguments are
+/// returned untouched.
+pub fn parse_options<I: Iterator<Item = String>>(
+    mut args: I,
+) -> Result<(Option<Options>, Vec<String>), MyError> {
+    let mut options = None;
+    let mut rest = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--window" => {
+                let value = option_value(&mut args, "--window")?;
+                let window = value
+                    .parse()
+                    .ok()
+                    .filter(|&window| window > 0)
+                    .ok_or_else(|| {
+                        MyError::InvalidArgument(format!("Invalid window size '{}'", value))
+                    })?;
+                options
+                    .get_or_insert_with(Options::default)
+                    .windows
+                    .push(window);
+            }
+            "--compare" => {
+                let comparison = option_value(&mut args, "--compare")?.parse()?;
+                options.get_or_insert_with(Options::default).comparison = comparison;
+            }
+            "--stream" => {
+                options.get_or_insert_with(Options::default);
+            }
+            "--longest-run" => options.get_or_insert_with(Options::default).longest_run = true,
+            _ => rest.push(arg),
+        }
+    }
+    Ok((options, rest))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub options: Options,
+    pub stats: Vec<WindowStats>,
+}
+
+/// Reads `source` once, line by line, without holding it in memory.
+pub fn analyse_source(source: &InputSource, options: Options) -> Result<Analysis, MyError> {
+    let windows = if options.windows.is_empty() {
+        vec![1, 3]
+    } else {
+        options.windows.clone()
+    };
+    let mut analyser = StreamAnalyser::new(&windows, options.comparison);
+    analyser.read(source.name(), source.reader()?)?;
+
+    Ok(Analysis {
+        options,
+        stats: analyser.stats(),
+    })
+}
+
+impl Analysis {
+    /// The puzzle answers, which are the counts for windows 1 and 3 of an
+    /// analysis with the default options.
+    pub fn part_results(&self) -> Vec<PartResult> {
+        [(1, 1), (2, 3)]
+            .iter()
+            .map(|&(part, window)| {
+                let count = self
+                    .stats
+                    .iter()
+                    .find(|stats| stats.window == window)
+                    .map(|stats| stats.count)
+                    .ok_or_else(|| MyError::NoAnswer(format!("no window of {}", window)));
+                PartResult::new(SonarSweep::DAY, part, count)
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> Value {
+        let comparison = self.options.comparison.name();
+        let windows = self
+            .stats
+            .iter()
+            .map(|stats| {
+                let mut json = json!({
+                    "window": stats.window,
+                    "comparison": comparison,
+                    "count": stats.count,
+                });
+                if let (true, Some(run)) = (self.options.longest_run, stats.longest_run) {
+                    json["longest_run"] = json!({
+                        "start": run.start,
+                        "length": run.len,
+                        "from": run.from,
+                        "to": run.to,
+                    });
+                }
+                json
+            })
+            .collect::<Vec<_>>();
+        Value::from(windows)
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, stats) in self.stats.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "Window {}: {} {}",
+                stats.window,
+                stats.count,
+                self.options.comparison.name()
+            )?;
+            if let (true, Some(run)) = (self.options.longest_run, stats.longest_run) {
+                write!(
+                    f,
+                    "\n  longest increasing run: {} windows from {} to {} (windows {}-{})",
+                    run.len,
+                    run.from,
+                    run.to,
+                    run.start + 1,
+                    run.start + run.len
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let (options, rest) = parse_options(args("input.txt --format json")).unwrap();
+        assert_eq!(None, options);
+        assert_eq!(vec!["input.txt", "--format", "json"], rest);
+
+        let (options, rest) =
+            parse_options(args("--window 3 - --compare plateaus --window 5")).unwrap();
+        let expected = Options {
+            windows: vec![3, 5],
+            comparison: Comparison::Plateaus,
+            longest_run: false,
+        };
+        assert_eq!(Some(expected), options);
+        assert_eq!(vec!["-"], rest);
+
+        let (options, _) = parse_options(args("--stream -")).unwrap();
+        assert_eq!(Some(Options::default()), options);
+
+        assert!(parse_options(args("--window 0")).is_err());
+        assert!(parse_options(args("--compare sideways")).is_err());
+    }
+
+    #[test]
+    fn test_analyse_source() {
+        let source =
+            InputSource::Str("199\n200\n208\n210\n200\n207\n240\n269\n260\n263".to_string());
+        let options = Options {
+            longest_run: true,
+            ..Options::default()
+        };
+
+        let analysis = analyse_source(&source, options).unwrap();
+
+        assert_eq!(
+            "Window 1: 7 increases\n  longest increasing run: 4 windows from 199 to 210 (windows 1-4)\n\
+             Window 3: 5 increases\n  longest increasing run: 5 windows from 617 to 792 (windows 4-8)",
+            analysis.to_string()
+        );
+        assert_eq!(5, analysis.to_json()[1]["longest_run"]["length"]);
+
+        let answers = analysis
+            .part_results()
+            .into_iter()
+            .map(|result| (result.part, result.answer.unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(1, "7".to_string()), (2, "5".to_string())], answers);
+    }
+}
@@ -0,0 +1,272 @@
+pub mod analysis;
+
+use shared::{InputLine, MyError, PuzzleInput, SlidingWindow, Solution};
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub struct SonarSweep;
+
+impl Solution for SonarSweep {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        input
+            .lines()
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| line.parse(line.text.trim(), "integer"))
+            .collect()
+    }
+
+    fn part1(depths: &Self::Input) -> Result<Self::Answer1, MyError> {
+        Ok(analyse(depths, &[1], Comparison::Increases)[0].count)
+    }
+
+    fn part2(depths: &Self::Input) -> Result<Self::Answer2, MyError> {
+        Ok(analyse(depths, &[3], Comparison::Increases)[0].count)
+    }
+}
+
+/// How consecutive windows are compared when counting changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Increases,
+    Decreases,
+    Plateaus,
+}
+
+impl Comparison {
+    pub fn matches(self, prev: i64, next: i64) -> bool {
+        match self {
+            Comparison::Increases => next > prev,
+            Comparison::Decreases => next < prev,
+            Comparison::Plateaus => next == prev,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Comparison::Increases => "increases",
+            Comparison::Decreases => "decreases",
+            Comparison::Plateaus => "plateaus",
+        }
+    }
+}
+
+impl FromStr for Comparison {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "increases" => Ok(Comparison::Increases),
+            "decreases" => Ok(Comparison::Decreases),
+            "plateaus" => Ok(Comparison::Plateaus),
+            other => Err(MyError::InvalidArgument(format!(
+                "Unknown comparison '{}', expected increases, decreases or plateaus",
+                other
+            ))),
+        }
+    }
+}
+
+/// Longest stretch of strictly increasing window sums.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    /// Index of the first window in the run.
+    pub start: usize,
+    /// Number of windows in the run.
+    pub len: usize,
+    pub from: i64,
+    pub to: i64,
+}
+
+/// Result of comparing every pair of consecutive windows of one size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowStats {
+    pub window: usize,
+    pub count: usize,
+    /// The first of the longest increasing runs, `None` if no window filled.
+    pub longest_run: Option<Run>,
+}
+
+/// Single pass over a stream of depths for any number of window sizes at
+/// once, keeping only the last `window` depths of each in memory.
+pub struct StreamAnalyser {
+    comparison: Comparison,
+    trackers: Vec<Tracker>,
+}
+
+impl StreamAnalyser {
+    /// Panics if any window size is zero.
+    pub fn new(windows: &[usize], comparison: Comparison) -> Self {
+        StreamAnalyser {
+            comparison,
+            trackers: windows.iter().map(|&size| Tracker::new(size)).collect(),
+        }
+    }
+
+    pub fn push(&mut self, depth: i32) {
+        for tracker in &mut self.trackers {
+            tracker.push(depth as i64, self.comparison);
+        }
+    }
+
+    /// Pushes one depth per non-blank line of `reader`, naming `path` in
+    /// parse errors.
+    pub fn read<R: BufRead>(&mut self, path: &str, reader: R) -> Result<(), MyError> {
+        for (idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = InputLine::new(path, idx + 1, &line);
+            if !line.text.trim().is_empty() {
+                self.push(line.parse(line.text.trim(), "integer")?);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn stats(&self) -> Vec<WindowStats> {
+        self.trackers
+            .iter()
+            .map(|tracker| WindowStats {
+                window: tracker.sums.size(),
+                count: tracker.count,
+                longest_run: tracker.longest,
+            })
+            .collect()
+    }
+}
+
+struct Tracker {
+    sums: SlidingWindow<i64>,
+    filled: usize,
+    count: usize,
+    run: Option<Run>,
+    longest: Option<Run>,
+}
+
+impl Tracker {
+    fn new(size: usize) -> Self {
+        Tracker {
+            sums: SlidingWindow::new(size),
+            filled: 0,
+            count: 0,
+            run: None,
+            longest: None,
+        }
+    }
+
+    fn push(&mut self, depth: i64, comparison: Comparison) {
+        self.sums.push(depth);
+        if !self.sums.is_full() {
+            return;
+        }
+        let sum = self.sums.sum();
+
+        let run = match self.run {
+            Some(run) => {
+                if comparison.matches(run.to, sum) {
+                    self.count += 1;
+                }
+                if sum > run.to {
+                    Run {
+                        len: run.len + 1,
+                        to: sum,
+                        ..run
+                    }
+                } else {
+                    Run::starting(self.filled, sum)
+                }
+            }
+            None => Run::starting(self.filled, sum),
+        };
+        if self.longest.is_none_or(|longest| run.len > longest.len) {
+            self.longest = Some(run);
+        }
+        self.run = Some(run);
+        self.filled += 1;
+    }
+}
+
+impl Run {
+    fn starting(start: usize, sum: i64) -> Self {
+        Run {
+            start,
+            len: 1,
+            from: sum,
+            to: sum,
+        }
+    }
+}
+
+pub fn analyse(depths: &[i32], windows: &[usize], comparison: Comparison) -> Vec<WindowStats> {
+    let mut analyser = StreamAnalyser::new(windows, comparison);
+    for &depth in depths {
+        analyser.push(depth);
+    }
+    analyser.stats()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+    #[test]
+    fn test_count_increases() {
+        let depths = SonarSweep::parse(&PuzzleInput::new("example", EXAMPLE)).unwrap();
+
+        assert_eq!(7, SonarSweep::part1(&depths).unwrap());
+        assert_eq!(5, SonarSweep::part2(&depths).unwrap());
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let depths = SonarSweep::parse(&PuzzleInput::new("example", EXAMPLE)).unwrap();
+        let count = |window, comparison| analyse(&depths, &[window], comparison)[0].count;
+
+        assert_eq!(2, count(1, Comparison::Decreases));
+        assert_eq!(1, count(3, Comparison::Plateaus));
+        assert_eq!(0, count(10, Comparison::Increases));
+    }
+
+    #[test]
+    fn test_longest_increasing_run() {
+        let depths = SonarSweep::parse(&PuzzleInput::new("example", EXAMPLE)).unwrap();
+
+        let stats = analyse(&depths, &[1, 3, 11], Comparison::Increases);
+
+        let run = Run {
+            start: 0,
+            len: 4,
+            from: 199,
+            to: 210,
+        };
+        assert_eq!(Some(run), stats[0].longest_run);
+        let run = Run {
+            start: 3,
+            len: 5,
+            from: 617,
+            to: 792,
+        };
+        assert_eq!(Some(run), stats[1].longest_run);
+        assert_eq!(None, stats[2].longest_run);
+    }
+
+    #[test]
+    fn test_stream_reports_line() {
+        let mut analyser = StreamAnalyser::new(&[1, 3], Comparison::Increases);
+        analyser.read("<stdin>", EXAMPLE.as_bytes()).unwrap();
+
+        let counts = analyser.stats().iter().map(|s| s.count).collect::<Vec<_>>();
+        assert_eq!(vec![7, 5], counts);
+
+        let err = analyser
+            .read("<stdin>", "1\n\n3x\n".as_bytes())
+            .unwrap_err();
+        assert_eq!("<stdin>:3:1: expected integer, got '3x'", err.to_string());
+    }
+}
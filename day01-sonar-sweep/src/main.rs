@@ -1,68 +1,23 @@
-mod sliding_window;
-
-use shared::{read_first_arg, MyError};
-use sliding_window::SlidingWindow;
-use std::fs::File;
-use std::io::prelude::*;
-use std::io::BufReader;
+use day01_sonar_sweep::analysis::{analyse_source, parse_options};
+use shared::{parse_main_args, print_results, Format, MyError};
 
 fn main() -> Result<(), MyError> {
-    let input = read_first_arg()?;
-
-    count_increases(&input)?;
-    count_increases_sliding_window(&input)?;
-
-    Ok(())
-}
-
-fn count_increases(file_path: &str) -> Result<(), MyError> {
-    let f = File::open(file_path)?;
-    let f = BufReader::new(f);
-
-    let mut prev: Option<i32> = None;
-    let mut counter: usize = 0;
-    for line in f.lines() {
-        let depth: i32 = line.unwrap().parse().unwrap();
-        if let Some(prev_depth) = prev {
-            if depth > prev_depth {
-                counter += 1;
+    let (options, rest) = parse_options(std::env::args().skip(1))?;
+    let args = parse_main_args(rest.into_iter())?;
+
+    match options {
+        Some(options) => {
+            let analysis = analyse_source(&args.input, options)?;
+            match args.format {
+                Format::Text => println!("{}", analysis),
+                Format::Json => println!("{}", analysis.to_json()),
             }
+            Ok(())
         }
-        prev = Some(depth);
-    }
-    println!("Number of depth increases: {}", counter);
-    Ok(())
-}
-
-fn count_increases_sliding_window(file_path: &str) -> Result<(), MyError> {
-    let f = File::open(file_path)?;
-    let f = BufReader::new(f);
-
-    let window_size: usize = 3;
-
-    let mut counter: usize = 0;
-    let mut window1 = SlidingWindow::new(window_size);
-    let mut window2 = SlidingWindow::new(window_size);
-
-    let mut lines = f.lines();
-    if let Some(line) = lines.next() {
-        let depth: i32 = line.unwrap().parse().unwrap();
-        window1.add(depth)
-    }
-
-    for line in lines {
-        let depth: i32 = line.unwrap().parse().unwrap();
-        window2.add(depth);
-        if window2.is_full() {
-            if window2.sum > window1.sum {
-                counter += 1;
-            }
+        // Both parts come from one streaming pass with windows 1 and 3
+        None => {
+            let analysis = analyse_source(&args.input, Default::default())?;
+            print_results(analysis.part_results(), args.format)
         }
-        window1.add(depth);
     }
-    println!(
-        "Number of depth increases with window size {}: {}",
-        window_size, counter
-    );
-    Ok(())
 }
@@ -0,0 +1,194 @@
+use crate::submarine::{State, Submarine};
+use shared::{InputLine, MyError};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+    /// A command registered with `CommandSet::define`.
+    Custom {
+        name: String,
+        value: Option<i32>,
+    },
+}
+
+/// Action of a user-defined command. It gets the command's value if it
+/// takes one.
+pub type Action = fn(&mut State, Option<i32>);
+
+struct Definition {
+    takes_value: bool,
+    action: Action,
+}
+
+/// Commands understood by the parser and interpreter: `forward`, `down` and
+/// `up`, plus any defined on top of them.
+#[derive(Default)]
+pub struct CommandSet {
+    custom: HashMap<String, Definition>,
+}
+
+impl CommandSet {
+    pub fn standard() -> Self {
+        CommandSet::default()
+    }
+
+    /// The standard commands plus `back <n>`, which moves back horizontally,
+    /// and `reset`, which returns to the surface at the start.
+    pub fn extended() -> Self {
+        CommandSet::standard()
+            .insert("back", true, |state, value| {
+                state.horizontal -= value.unwrap_or_default()
+            })
+            .insert("reset", false, |state, _| *state = State::default())
+    }
+
+    /// Adds a command, replacing any earlier one with the same name. The
+    /// built-in commands can't be redefined.
+    pub fn define(self, name: &str, takes_value: bool, action: Action) -> Result<Self, MyError> {
+        if ["forward", "down", "up"].contains(&name) {
+            return Err(MyError::InvalidArgument(format!(
+                "'{}' is a built-in command",
+                name
+            )));
+        }
+        Ok(self.insert(name, takes_value, action))
+    }
+
+    fn insert(mut self, name: &str, takes_value: bool, action: Action) -> Self {
+        self.custom.insert(
+            name.to_owned(),
+            Definition {
+                takes_value,
+                action,
+            },
+        );
+        self
+    }
+
+    pub fn parse(&self, line: &InputLine) -> Result<Command, MyError> {
+        let text = line.text.trim();
+        let name = text.split_whitespace().next().unwrap_or(text);
+        let takes_value = match name {
+            "forward" | "down" | "up" => true,
+            _ => match self.custom.get(name) {
+                Some(definition) => definition.takes_value,
+                None => return Err(line.error(name, "command")),
+            },
+        };
+
+        let value = if takes_value {
+            let (_, value) = line.split_once(text, " ")?;
+            Some(line.parse(value.trim(), "integer")?)
+        } else if text != name {
+            return Err(line.error(text[name.len()..].trim(), "end of line"));
+        } else {
+            None
+        };
+
+        Ok(match (name, value) {
+            ("forward", Some(value)) => Command::Forward(value),
+            ("down", Some(value)) => Command::Down(value),
+            ("up", Some(value)) => Command::Up(value),
+            (name, value) => Command::Custom {
+                name: name.to_owned(),
+                value,
+            },
+        })
+    }
+
+    pub fn execute<S: Submarine>(
+        &self,
+        submarine: &mut S,
+        command: &Command,
+    ) -> Result<(), MyError> {
+        match command {
+            Command::Forward(units) => submarine.forward(*units),
+            Command::Down(units) => submarine.down(*units),
+            Command::Up(units) => submarine.up(*units),
+            Command::Custom { name, value } => {
+                let definition = self.custom.get(name).ok_or_else(|| {
+                    MyError::InvalidArgument(format!("Unknown command '{}'", name))
+                })?;
+                (definition.action)(submarine.state_mut(), *value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs every command in order and returns where the submarine ends up.
+    pub fn run<S: Submarine>(
+        &self,
+        mut submarine: S,
+        commands: &[Command],
+    ) -> Result<State, MyError> {
+        for command in commands {
+            self.execute(&mut submarine, command)?;
+        }
+        Ok(submarine.state())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::submarine::PlainSubmarine;
+
+    #[test]
+    fn test_parse() {
+        let commands = CommandSet::extended();
+        let parse = |text| commands.parse(&InputLine::new("course", 1, text));
+
+        assert_eq!(Command::Up(3), parse("up 3").unwrap());
+        assert_eq!(
+            Command::Custom {
+                name: "back".to_string(),
+                value: Some(2)
+            },
+            parse("back 2").unwrap()
+        );
+        assert_eq!(
+            "course:1:7: expected end of line, got '1'",
+            parse("reset 1").unwrap_err().to_string()
+        );
+        assert!(CommandSet::standard()
+            .parse(&InputLine::new("course", 1, "back 2"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_user_defined_commands() {
+        let commands = CommandSet::extended()
+            .define("surface", false, |state, _| state.depth = 0)
+            .unwrap();
+        let course = [
+            "forward 5",
+            "down 5",
+            "back 2",
+            "surface",
+            "down 1",
+            "reset",
+            "up 4",
+        ]
+        .iter()
+        .map(|text| commands.parse(&InputLine::new("course", 1, text)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+        let state = commands
+            .run(PlainSubmarine::default(), &course[..5])
+            .unwrap();
+        assert_eq!((3, 1), (state.horizontal, state.depth));
+
+        let state = commands.run(PlainSubmarine::default(), &course).unwrap();
+        assert_eq!((0, -4), (state.horizontal, state.depth));
+
+        let redefined = CommandSet::standard().define("up", true, |state, _| state.depth = 0);
+        assert_eq!(
+            "'up' is a built-in command",
+            redefined.err().unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,87 @@
+pub mod command;
+pub mod options;
+pub mod submarine;
+pub mod trace;
+
+use command::{Command, CommandSet};
+use shared::{MyError, PuzzleInput, Solution};
+use submarine::{AimSubmarine, PlainSubmarine, Submarine};
+
+pub struct Dive;
+
+impl Solution for Dive {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        read_course(input, &CommandSet::standard())
+    }
+
+    fn part1(course: &Self::Input) -> Result<Self::Answer1, MyError> {
+        final_product(&CommandSet::standard(), PlainSubmarine::default(), course)
+    }
+
+    fn part2(course: &Self::Input) -> Result<Self::Answer2, MyError> {
+        final_product(&CommandSet::standard(), AimSubmarine::default(), course)
+    }
+}
+
+/// Reads one command per line using the commands `commands` knows.
+pub fn read_course(input: &PuzzleInput, commands: &CommandSet) -> Result<Vec<Command>, MyError> {
+    input
+        .lines()
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| commands.parse(&line))
+        .collect()
+}
+
+pub fn final_product<S: Submarine>(
+    commands: &CommandSet,
+    submarine: S,
+    course: &[Command],
+) -> Result<i32, MyError> {
+    let state = commands.run(submarine, course)?;
+    Ok(state.horizontal * state.depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
+    #[test]
+    fn test_calculate_position() {
+        let commands = Dive::parse(&PuzzleInput::new("example", EXAMPLE)).unwrap();
+
+        assert_eq!(150, Dive::part1(&commands).unwrap());
+        assert_eq!(900, Dive::part2(&commands).unwrap());
+    }
+
+    #[test]
+    fn test_extended_course() {
+        let input = PuzzleInput::new("course", "forward 5\ndown 2\nback 3\n");
+        let commands = CommandSet::extended();
+
+        let course = read_course(&input, &commands).unwrap();
+
+        let product = final_product(&commands, PlainSubmarine::default(), &course).unwrap();
+        assert_eq!(4, product);
+        assert!(Dive::parse(&input).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_command() {
+        let input = PuzzleInput::new("input.txt", "forward 5\nforwrd 3");
+
+        let err = Dive::parse(&input).unwrap_err();
+
+        assert_eq!(
+            "input.txt:2:1: expected command, got 'forwrd'",
+            err.to_string()
+        );
+    }
+}
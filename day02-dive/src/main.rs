@@ -1,66 +1,34 @@
-use shared::{file_lines, read_first_arg, MyError};
+use day02_dive::options::parse_options;
+use day02_dive::submarine::{AimSubmarine, PlainSubmarine};
+use day02_dive::trace::trace;
+use day02_dive::{final_product, read_course, Dive};
+use shared::{parse_main_args, print_results, MyError, PartResult, Solution};
 
 fn main() -> Result<(), MyError> {
-    let input = read_first_arg()?;
-
-    calculate_position(&input)?;
-    calculate_position_with_aim(&input)?;
-
-    Ok(())
-}
-
-fn calculate_position(file_path: &str) -> Result<(), MyError> {
-    let mut horiz = 0;
-    let mut vert = 0;
-
-    for line in file_lines(file_path)? {
-        match parse(&line.unwrap()) {
-            ("forward", val) => horiz += val,
-            ("down", val) => vert += val,
-            ("up", val) => vert -= val,
-            _ => eprintln!("Invalid command"),
+    let (options, rest) = parse_options(std::env::args().skip(1))?;
+    let args = parse_main_args(rest.into_iter())?;
+    let commands = options.commands();
+    let course = read_course(&args.input.read()?, &commands)?;
+
+    match options.trace {
+        Some(trace_options) => {
+            print!("{}", trace(&commands, &course, trace_options)?);
+            Ok(())
         }
-    }
-
-    println!(
-        "Horizontal({}) x Vertical({}) = {}",
-        horiz,
-        vert,
-        horiz * vert,
-    );
-
-    Ok(())
-}
-
-fn calculate_position_with_aim(file_path: &str) -> Result<(), MyError> {
-    let mut horiz = 0;
-    let mut depth = 0;
-    let mut aim = 0;
-
-    for line in file_lines(file_path)? {
-        match parse(&line.unwrap()) {
-            ("forward", val) => {
-                horiz += val;
-                depth += aim * val;
-            }
-            ("down", val) => aim += val,
-            ("up", val) => aim -= val,
-            _ => eprintln!("Invalid command"),
+        None => {
+            let results = vec![
+                PartResult::new(
+                    Dive::DAY,
+                    1,
+                    final_product(&commands, PlainSubmarine::default(), &course),
+                ),
+                PartResult::new(
+                    Dive::DAY,
+                    2,
+                    final_product(&commands, AimSubmarine::default(), &course),
+                ),
+            ];
+            print_results(results, args.format)
         }
     }
-
-    println!(
-        "Horizontal({}) x Depth({}) = {}",
-        horiz,
-        depth,
-        horiz * depth,
-    );
-
-    Ok(())
-}
-
-fn parse(line: &str) -> (&str, i32) {
-    line.split_once(" ")
-        .map(|s| (s.0, s.1.parse::<i32>().unwrap()))
-        .unwrap()
 }
@@ -0,0 +1,71 @@
+use crate::command::CommandSet;
+use crate::trace::{Semantics, TraceOptions};
+use shared::{option_value, MyError};
+
+/// Options of the day's own binary on top of the common ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Print a trace instead of the two puzzle answers.
+    pub trace: Option<TraceOptions>,
+    /// Accept the extended commands as well as the standard ones.
+    pub extended: bool,
+}
+
+impl Options {
+    pub fn commands(&self) -> CommandSet {
+        if self.extended {
+            CommandSet::extended()
+        } else {
+            CommandSet::standard()
+        }
+    }
+}
+
+/// Takes `--trace csv|ascii|svg`, `--semantics plain|aim` (aim by default)
+/// and `--extended` out of `args`, returning the other arguments untouched.
+pub fn parse_options<I: Iterator<Item = String>>(
+    mut args: I,
+) -> Result<(Options, Vec<String>), MyError> {
+    let mut format = None;
+    let mut semantics = Semantics::Aim;
+    let mut extended = false;
+    let mut rest = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--trace" => format = Some(option_value(&mut args, "--trace")?.parse()?),
+            "--semantics" => semantics = option_value(&mut args, "--semantics")?.parse()?,
+            "--extended" => extended = true,
+            _ => rest.push(arg),
+        }
+    }
+    let trace = format.map(|format| TraceOptions { format, semantics });
+    Ok((Options { trace, extended }, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::TraceFormat;
+
+    #[test]
+    fn test_parse_options() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        let (options, rest) = parse_options(args("- --trace svg").into_iter()).unwrap();
+        let expected = TraceOptions {
+            format: TraceFormat::Svg,
+            semantics: Semantics::Aim,
+        };
+        assert_eq!(Some(expected), options.trace);
+        assert!(!options.extended);
+        assert_eq!(vec!["-"], rest);
+
+        let (options, _) = parse_options(args("--semantics plain").into_iter()).unwrap();
+        assert_eq!(None, options.trace);
+        assert!(parse_options(args("--trace png").into_iter()).is_err());
+
+        let (options, _) = parse_options(args("--extended -").into_iter()).unwrap();
+        assert!(options.extended);
+    }
+}
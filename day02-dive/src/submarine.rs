@@ -0,0 +1,67 @@
+/// Where a submarine is and which way it is pointing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct State {
+    pub horizontal: i32,
+    pub depth: i32,
+    pub aim: i32,
+}
+
+/// How a submarine responds to the three built-in commands.
+pub trait Submarine {
+    fn state(&self) -> State;
+
+    fn state_mut(&mut self) -> &mut State;
+
+    fn forward(&mut self, units: i32);
+
+    fn down(&mut self, units: i32);
+
+    fn up(&mut self, units: i32) {
+        self.down(-units);
+    }
+}
+
+/// `down` and `up` change the depth directly.
+#[derive(Debug, Default)]
+pub struct PlainSubmarine(pub State);
+
+impl Submarine for PlainSubmarine {
+    fn state(&self) -> State {
+        self.0
+    }
+
+    fn state_mut(&mut self) -> &mut State {
+        &mut self.0
+    }
+
+    fn forward(&mut self, units: i32) {
+        self.0.horizontal += units;
+    }
+
+    fn down(&mut self, units: i32) {
+        self.0.depth += units;
+    }
+}
+
+/// `down` and `up` change the aim, and moving forward dives along it.
+#[derive(Debug, Default)]
+pub struct AimSubmarine(pub State);
+
+impl Submarine for AimSubmarine {
+    fn state(&self) -> State {
+        self.0
+    }
+
+    fn state_mut(&mut self) -> &mut State {
+        &mut self.0
+    }
+
+    fn forward(&mut self, units: i32) {
+        self.0.horizontal += units;
+        self.0.depth += self.0.aim * units;
+    }
+
+    fn down(&mut self, units: i32) {
+        self.0.aim += units;
+    }
+}
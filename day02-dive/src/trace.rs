@@ -0,0 +1,255 @@
+use crate::command::{Command, CommandSet};
+use crate::submarine::{AimSubmarine, PlainSubmarine, State, Submarine};
+use shared::{Grid, MyError};
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// How a trajectory is written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    Csv,
+    Ascii,
+    Svg,
+}
+
+impl FromStr for TraceFormat {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(TraceFormat::Csv),
+            "ascii" => Ok(TraceFormat::Ascii),
+            "svg" => Ok(TraceFormat::Svg),
+            other => Err(MyError::InvalidArgument(format!(
+                "Unknown trace format '{}', expected csv, ascii or svg",
+                other
+            ))),
+        }
+    }
+}
+
+/// Which commands' semantics a trace follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Semantics {
+    Plain,
+    Aim,
+}
+
+impl FromStr for Semantics {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Semantics::Plain),
+            "aim" => Ok(Semantics::Aim),
+            other => Err(MyError::InvalidArgument(format!(
+                "Unknown semantics '{}', expected plain or aim",
+                other
+            ))),
+        }
+    }
+}
+
+/// Trace requested on the command line instead of the two puzzle answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceOptions {
+    pub format: TraceFormat,
+    pub semantics: Semantics,
+}
+
+/// State before the first command and after each one.
+pub fn trajectory<S: Submarine>(
+    commands: &CommandSet,
+    mut submarine: S,
+    course: &[Command],
+) -> Result<Vec<State>, MyError> {
+    let mut states = vec![submarine.state()];
+    for command in course {
+        commands.execute(&mut submarine, command)?;
+        states.push(submarine.state());
+    }
+    Ok(states)
+}
+
+/// Runs `course` with `commands` and renders its trajectory.
+pub fn trace(
+    commands: &CommandSet,
+    course: &[Command],
+    options: TraceOptions,
+) -> Result<String, MyError> {
+    let states = match options.semantics {
+        Semantics::Plain => trajectory(commands, PlainSubmarine::default(), course)?,
+        Semantics::Aim => trajectory(commands, AimSubmarine::default(), course)?,
+    };
+    Ok(render(&states, options.format))
+}
+
+pub fn render(states: &[State], format: TraceFormat) -> String {
+    match format {
+        TraceFormat::Csv => to_csv(states),
+        TraceFormat::Ascii => ascii_plot(states, 72, 20),
+        TraceFormat::Svg => svg_plot(states),
+    }
+}
+
+pub fn to_csv(states: &[State]) -> String {
+    let mut csv = String::from("step,horizontal,depth,aim\n");
+    for (step, state) in states.iter().enumerate() {
+        writeln!(
+            csv,
+            "{},{},{},{}",
+            step, state.horizontal, state.depth, state.aim
+        )
+        .unwrap();
+    }
+    csv
+}
+
+/// Depth profile with the surface at the top and horizontal position
+/// increasing to the right, scaled to fit `width` by `height` characters.
+pub fn ascii_plot(states: &[State], width: usize, height: usize) -> String {
+    let bounds = Bounds::of(states);
+    let mut plot = Grid::from_fn(height, width, |_| ' ');
+    let scale = |state: &State| {
+        (
+            scale(state.depth - bounds.min_depth, bounds.depth_range(), height),
+            scale(
+                state.horizontal - bounds.min_horizontal,
+                bounds.horizontal_range(),
+                width,
+            ),
+        )
+    };
+
+    for pair in states.windows(2) {
+        let (from, to) = (scale(&pair[0]), scale(&pair[1]));
+        let steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1)).max(1);
+        for step in 0..=steps {
+            let row = interpolate(from.0, to.0, step, steps);
+            let col = interpolate(from.1, to.1, step, steps);
+            plot[(row, col)] = '.';
+        }
+    }
+    for state in states {
+        plot[scale(state)] = '*';
+    }
+
+    format!(
+        "depth {}..{}, horizontal {}..{}\n{}",
+        bounds.min_depth,
+        bounds.min_depth + bounds.depth_range(),
+        bounds.min_horizontal,
+        bounds.min_horizontal + bounds.horizontal_range(),
+        plot
+    )
+}
+
+/// Depth profile as a polyline, in the trajectory's own units.
+pub fn svg_plot(states: &[State]) -> String {
+    let bounds = Bounds::of(states);
+    let points = states
+        .iter()
+        .map(|state| format!("{},{}", state.horizontal, state.depth))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" preserveAspectRatio=\"none\">\n\
+         <polyline points=\"{}\" fill=\"none\" stroke=\"black\" vector-effect=\"non-scaling-stroke\"/>\n\
+         </svg>\n",
+        bounds.min_horizontal,
+        bounds.min_depth,
+        bounds.horizontal_range(),
+        bounds.depth_range(),
+        points
+    )
+}
+
+struct Bounds {
+    min_horizontal: i32,
+    max_horizontal: i32,
+    min_depth: i32,
+    max_depth: i32,
+}
+
+impl Bounds {
+    fn of(states: &[State]) -> Self {
+        let horizontal = states.iter().map(|state| state.horizontal);
+        let depth = states.iter().map(|state| state.depth);
+        Bounds {
+            min_horizontal: horizontal.clone().min().unwrap_or_default(),
+            max_horizontal: horizontal.max().unwrap_or_default(),
+            min_depth: depth.clone().min().unwrap_or_default(),
+            max_depth: depth.max().unwrap_or_default(),
+        }
+    }
+
+    // Never zero, so that a flat course still has something to scale by.
+    fn horizontal_range(&self) -> i32 {
+        (self.max_horizontal - self.min_horizontal).max(1)
+    }
+
+    fn depth_range(&self) -> i32 {
+        (self.max_depth - self.min_depth).max(1)
+    }
+}
+
+/// Maps `offset` in `0..=range` onto `0..cells`.
+fn scale(offset: i32, range: i32, cells: usize) -> usize {
+    (offset as i64 * (cells as i64 - 1) / range as i64) as usize
+}
+
+fn interpolate(from: usize, to: usize, step: usize, steps: usize) -> usize {
+    (from as i64 + (to as i64 - from as i64) * step as i64 / steps as i64) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::InputLine;
+
+    fn example() -> Vec<State> {
+        let commands = CommandSet::standard();
+        let course = [
+            "forward 5",
+            "down 5",
+            "forward 8",
+            "up 3",
+            "down 8",
+            "forward 2",
+        ]
+        .iter()
+        .map(|text| commands.parse(&InputLine::new("example", 1, text)).unwrap())
+        .collect::<Vec<_>>();
+        trajectory(&commands, AimSubmarine::default(), &course).unwrap()
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let csv = to_csv(&example());
+
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(8, lines.len());
+        assert_eq!("step,horizontal,depth,aim", lines[0]);
+        assert_eq!("3,13,40,5", lines[4]);
+        assert_eq!("6,15,60,10", lines[7]);
+    }
+
+    #[test]
+    fn test_plots() {
+        let plot = ascii_plot(&example(), 16, 4);
+
+        let expected = [
+            "depth 0..60, horizontal 0..15",
+            "*....*...       ",
+            "         ....   ",
+            "             *. ",
+            "               *",
+        ];
+        assert_eq!(expected.join("\n") + "\n", plot);
+
+        let svg = svg_plot(&example());
+        assert!(svg.contains("viewBox=\"0 0 15 60\""));
+        assert!(svg.contains("points=\"0,0 5,0 5,0 13,40 13,40 13,40 15,60\""));
+    }
+}
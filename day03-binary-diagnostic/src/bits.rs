@@ -0,0 +1,127 @@
+use shared::{InputLine, MyError};
+use std::fmt;
+
+/// Base of the limbs used for decimal arithmetic on wide values.
+const LIMB: u64 = 1_000_000_000;
+
+/// Binary number of any width, most significant bit first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bits(Vec<bool>);
+
+impl Bits {
+    pub fn new(bits: Vec<bool>) -> Self {
+        Bits(bits)
+    }
+
+    /// Reads the line's text as binary digits.
+    pub fn parse(line: &InputLine) -> Result<Self, MyError> {
+        let text = line.text.trim();
+        text.char_indices()
+            .map(|(idx, ch)| match ch {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(line.error(&text[idx..idx + ch.len_utf8()], "binary digit")),
+            })
+            .collect::<Result<_, _>>()
+            .map(Bits)
+    }
+
+    pub fn width(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Bit in column `col`, counting from the most significant.
+    pub fn get(&self, col: usize) -> bool {
+        self.0[col]
+    }
+
+    /// Every bit flipped.
+    pub fn inverted(&self) -> Self {
+        Bits(self.0.iter().map(|bit| !bit).collect())
+    }
+
+    /// `None` if the value doesn't fit in 64 bits.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.0.iter().try_fold(0u64, |value, &bit| {
+            value.checked_mul(2).map(|value| value + bit as u64)
+        })
+    }
+
+    /// Value in decimal, of any width.
+    pub fn to_decimal(&self) -> String {
+        self.product(&Bits(vec![true]))
+    }
+
+    /// `self * other` in decimal, of any width.
+    pub fn product(&self, other: &Bits) -> String {
+        let (a, b) = (self.limbs(), other.limbs());
+        let mut result = vec![0u64; a.len() + b.len()];
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &y) in b.iter().enumerate() {
+                let value = result[i + j] + x * y + carry;
+                result[i + j] = value % LIMB;
+                carry = value / LIMB;
+            }
+            result[i + b.len()] += carry;
+        }
+        while result.len() > 1 && result.last() == Some(&0) {
+            result.pop();
+        }
+
+        let mut limbs = result.iter().rev();
+        let mut decimal = limbs.next().unwrap().to_string();
+        for limb in limbs {
+            decimal += &format!("{:09}", limb);
+        }
+        decimal
+    }
+
+    /// Value in base `LIMB`, least significant limb first.
+    fn limbs(&self) -> Vec<u64> {
+        let mut limbs = vec![0];
+        for &bit in &self.0 {
+            let mut carry = bit as u64;
+            for limb in &mut limbs {
+                let value = *limb * 2 + carry;
+                *limb = value % LIMB;
+                carry = value / LIMB;
+            }
+            if carry > 0 {
+                limbs.push(carry);
+            }
+        }
+        limbs
+    }
+}
+
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &bit in &self.0 {
+            write!(f, "{}", if bit { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(text: &str) -> Bits {
+        Bits::parse(&InputLine::new("test", 1, text)).unwrap()
+    }
+
+    #[test]
+    fn test_decimal() {
+        assert_eq!("0", bits("000").to_decimal());
+        assert_eq!("198", bits("10110").product(&bits("01001")));
+        assert_eq!(u64::MAX.to_string(), bits(&"1".repeat(64)).to_decimal());
+
+        let wide = bits(&"1".repeat(70));
+        assert_eq!(
+            "1393796574908163946343621208799087771516929",
+            wide.product(&wide)
+        );
+    }
+}
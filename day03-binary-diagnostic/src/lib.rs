@@ -0,0 +1,405 @@
+pub mod bits;
+pub mod options;
+pub mod report;
+
+use bits::Bits;
+use serde_json::{json, Value};
+use shared::{Answer, MyError, PuzzleInput, Solution};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+pub struct BinaryDiagnostic;
+
+impl Solution for BinaryDiagnostic {
+    const DAY: u8 = 3;
+
+    type Input = (Vec<Bits>, usize);
+    type Answer1 = PowerConsumption;
+    type Answer2 = LifeSupport;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        get_readings(input)
+    }
+
+    fn part1((readings, width): &Self::Input) -> Result<Self::Answer1, MyError> {
+        calculate_power_consumption(readings, *width, TiePolicy::PreferOne)
+    }
+
+    fn part2((readings, width): &Self::Input) -> Result<Self::Answer2, MyError> {
+        calculate_life_support(readings, *width, TiePolicy::PreferOne)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerConsumption {
+    pub gamma_rate: Bits,
+    pub epsilon_rate: Bits,
+}
+
+impl fmt::Display for PowerConsumption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.gamma_rate.product(&self.epsilon_rate))
+    }
+}
+
+impl Answer for PowerConsumption {
+    fn details(&self) -> Option<Value> {
+        Some(json!({
+            "gamma_rate": rate_json(&self.gamma_rate),
+            "epsilon_rate": rate_json(&self.epsilon_rate),
+        }))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifeSupport {
+    pub oxygen_rate: Bits,
+    pub co2_rate: Bits,
+}
+
+impl fmt::Display for LifeSupport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.oxygen_rate.product(&self.co2_rate))
+    }
+}
+
+impl Answer for LifeSupport {
+    fn details(&self) -> Option<Value> {
+        Some(json!({
+            "oxygen_rate": rate_json(&self.oxygen_rate),
+            "co2_rate": rate_json(&self.co2_rate),
+        }))
+    }
+}
+
+/// A number if the rate fits in 64 bits, its decimal digits otherwise.
+fn rate_json(rate: &Bits) -> Value {
+    match rate.to_u64() {
+        Some(value) => json!(value),
+        None => json!(rate.to_decimal()),
+    }
+}
+
+/// Which bit counts as the most common when a column has as many ones as
+/// zeros. The least common bit is always the other one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TiePolicy {
+    PreferOne,
+    PreferZero,
+    Error,
+}
+
+impl FromStr for TiePolicy {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prefer-one" => Ok(TiePolicy::PreferOne),
+            "prefer-zero" => Ok(TiePolicy::PreferZero),
+            "error" => Ok(TiePolicy::Error),
+            other => Err(MyError::InvalidArgument(format!(
+                "Unknown tie policy '{}', expected prefer-one, prefer-zero or error",
+                other
+            ))),
+        }
+    }
+}
+
+impl TiePolicy {
+    /// Most common bit in column `col` of `readings`.
+    pub fn most_common(self, readings: &[Bits], col: usize) -> Result<bool, MyError> {
+        let ones = readings.iter().filter(|r| r.get(col)).count();
+        let zeros = readings.len() - ones;
+        match (ones.cmp(&zeros), self) {
+            (Ordering::Greater, _) | (Ordering::Equal, TiePolicy::PreferOne) => Ok(true),
+            (Ordering::Less, _) | (Ordering::Equal, TiePolicy::PreferZero) => Ok(false),
+            (Ordering::Equal, TiePolicy::Error) => Err(MyError::NoAnswer(format!(
+                "column {} has {} ones and {} zeros",
+                col, ones, zeros
+            ))),
+        }
+    }
+}
+
+pub fn calculate_power_consumption(
+    readings: &[Bits],
+    width: usize,
+    ties: TiePolicy,
+) -> Result<PowerConsumption, MyError> {
+    let gamma = Bits::new(
+        (0..width)
+            .map(|col| ties.most_common(readings, col))
+            .collect::<Result<_, _>>()?,
+    );
+    let epsilon = gamma.inverted();
+
+    Ok(PowerConsumption {
+        gamma_rate: gamma,
+        epsilon_rate: epsilon,
+    })
+}
+
+pub fn calculate_life_support(
+    readings: &[Bits],
+    width: usize,
+    ties: TiePolicy,
+) -> Result<LifeSupport, MyError> {
+    let oxygen = get_life_support_rate(readings, width, LifeSupportRatingType::MostCommon, ties)?;
+    let co2 = get_life_support_rate(readings, width, LifeSupportRatingType::LeastCommon, ties)?;
+
+    Ok(LifeSupport {
+        oxygen_rate: oxygen.rating,
+        co2_rate: co2.rating,
+    })
+}
+
+/// One column of the life support filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilterStep {
+    pub column: usize,
+    pub ones: usize,
+    pub zeros: usize,
+    pub kept: bool,
+    /// Readings left after dropping those without the kept bit.
+    pub remaining: usize,
+}
+
+/// A life support rating together with how it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    pub steps: Vec<FilterStep>,
+    pub rating: Bits,
+}
+
+/// Filters `readings` column by column until one is left. A column in which
+/// every remaining reading has the same bit keeps them all, so duplicate
+/// readings can survive to the end; they are all the same rating.
+pub fn get_life_support_rate(
+    readings: &[Bits],
+    width: usize,
+    rating_type: LifeSupportRatingType,
+    ties: TiePolicy,
+) -> Result<Filter, MyError> {
+    let mut readings = readings.to_vec();
+    let mut steps = vec![];
+    for column in 0..width {
+        if readings.len() <= 1 {
+            break;
+        }
+
+        let ones = readings.iter().filter(|r| r.get(column)).count();
+        let zeros = readings.len() - ones;
+        let kept = if ones == 0 || zeros == 0 {
+            ones > 0
+        } else {
+            let most_common = ties.most_common(&readings, column)?;
+            match rating_type {
+                LifeSupportRatingType::MostCommon => most_common,
+                LifeSupportRatingType::LeastCommon => !most_common,
+            }
+        };
+        readings.retain(|r| r.get(column) == kept);
+        steps.push(FilterStep {
+            column,
+            ones,
+            zeros,
+            kept,
+            remaining: readings.len(),
+        });
+    }
+
+    if readings.is_empty() {
+        return Err(MyError::NoAnswer("no readings to filter".to_string()));
+    }
+    Ok(Filter {
+        steps,
+        rating: readings.swap_remove(0),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifeSupportRatingType {
+    MostCommon,
+    LeastCommon,
+}
+
+/// Readings and their common width; every line must be as wide as the first.
+fn get_readings(input: &PuzzleInput) -> Result<(Vec<Bits>, usize), MyError> {
+    let mut readings: Vec<Bits> = vec![];
+    let mut lines = input.lines().filter(|line| !line.text.trim().is_empty());
+    let first_line = lines
+        .next()
+        .ok_or_else(|| input.unexpected_end("binary number"))?;
+    let width = Bits::parse(&first_line)?.width();
+
+    for line in std::iter::once(first_line).chain(lines) {
+        let reading = Bits::parse(&line)?;
+        if reading.width() != width {
+            let expected = format!("{} binary digits", width);
+            return Err(line.error(line.text.trim(), &expected));
+        }
+        readings.push(reading);
+    }
+    Ok((readings, width))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
+    fn rate(bits: &Bits) -> u64 {
+        bits.to_u64().unwrap()
+    }
+
+    fn readings(text: &str) -> Vec<Bits> {
+        BinaryDiagnostic::parse(&PuzzleInput::new("report", text))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_example() {
+        let input = BinaryDiagnostic::parse(&PuzzleInput::new("example", EXAMPLE)).unwrap();
+
+        let power = BinaryDiagnostic::part1(&input).unwrap();
+        assert_eq!(
+            (22, 9),
+            (rate(&power.gamma_rate), rate(&power.epsilon_rate))
+        );
+        assert_eq!("198", power.to_string());
+
+        let life_support = BinaryDiagnostic::part2(&input).unwrap();
+        assert_eq!(
+            (23, 10),
+            (
+                rate(&life_support.oxygen_rate),
+                rate(&life_support.co2_rate)
+            )
+        );
+        assert_eq!("230", life_support.to_string());
+    }
+
+    #[test]
+    fn test_odd_counts() {
+        // Two of five readings have the high bit set, which is not most common
+        let readings = readings("10\n10\n01\n01\n00");
+
+        let power = calculate_power_consumption(&readings, 2, TiePolicy::Error).unwrap();
+
+        assert_eq!(
+            (0b00, 0b11),
+            (rate(&power.gamma_rate), rate(&power.epsilon_rate))
+        );
+    }
+
+    #[test]
+    fn test_ties() {
+        let readings = readings("10\n01\n11\n00");
+        let power = |ties| calculate_power_consumption(&readings, 2, ties);
+        let life_support = |ties| calculate_life_support(&readings, 2, ties);
+
+        let prefer_one = power(TiePolicy::PreferOne).unwrap();
+        assert_eq!(
+            (0b11, 0b00),
+            (rate(&prefer_one.gamma_rate), rate(&prefer_one.epsilon_rate))
+        );
+        let prefer_zero = power(TiePolicy::PreferZero).unwrap();
+        assert_eq!(
+            (0b00, 0b11),
+            (
+                rate(&prefer_zero.gamma_rate),
+                rate(&prefer_zero.epsilon_rate)
+            )
+        );
+        assert_eq!(
+            "No answer: column 0 has 2 ones and 2 zeros",
+            power(TiePolicy::Error).unwrap_err().to_string()
+        );
+
+        let prefer_one = life_support(TiePolicy::PreferOne).unwrap();
+        assert_eq!(
+            (0b11, 0b00),
+            (rate(&prefer_one.oxygen_rate), rate(&prefer_one.co2_rate))
+        );
+        let prefer_zero = life_support(TiePolicy::PreferZero).unwrap();
+        assert_eq!(
+            (0b00, 0b11),
+            (rate(&prefer_zero.oxygen_rate), rate(&prefer_zero.co2_rate))
+        );
+        assert!(life_support(TiePolicy::Error).is_err());
+        assert!("prefer-none".parse::<TiePolicy>().is_err());
+    }
+
+    #[test]
+    fn test_filter_edge_cases() {
+        let life_support = |text: &str| {
+            let rates = calculate_life_support(&readings(text), 2, TiePolicy::PreferOne).unwrap();
+            (rate(&rates.oxygen_rate), rate(&rates.co2_rate))
+        };
+
+        assert_eq!((0b10, 0b10), life_support("10"));
+        // Both readings have the high bit set, so only the low bit filters
+        assert_eq!((0b11, 0b10), life_support("10\n11"));
+        assert_eq!((0b01, 0b01), life_support("01\n01"));
+        assert_eq!((0b01, 0b11), life_support("01\n01\n11"));
+
+        let filter = get_life_support_rate(
+            &readings("10\n11"),
+            2,
+            LifeSupportRatingType::LeastCommon,
+            TiePolicy::PreferOne,
+        )
+        .unwrap();
+        assert_eq!((true, 2), (filter.steps[0].kept, filter.steps[0].remaining));
+        assert!(
+            get_life_support_rate(&[], 2, LifeSupportRatingType::MostCommon, TiePolicy::Error)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_wide_readings() {
+        let wide = "1".repeat(40);
+        let input = PuzzleInput::new("report", &format!("{}\n0{}\n", wide, &wide[1..]));
+
+        let (readings, width) = BinaryDiagnostic::parse(&input).unwrap();
+
+        assert_eq!(40, width);
+        assert_eq!(Some((1 << 40) - 1), readings[0].to_u64());
+        assert_eq!(Some((1 << 39) - 1), readings[1].to_u64());
+
+        let wider = "1".repeat(65);
+        let input = PuzzleInput::new("report", &format!("{0}\n{0}\n0{1}\n", wider, &wider[1..]));
+        let input = BinaryDiagnostic::parse(&input).unwrap();
+
+        let power = BinaryDiagnostic::part1(&input).unwrap();
+        assert_eq!(None, power.gamma_rate.to_u64());
+        assert_eq!("36893488147419103231", power.gamma_rate.to_decimal());
+        assert_eq!("0", power.to_string());
+
+        let life_support = BinaryDiagnostic::part2(&input).unwrap();
+        assert_eq!(
+            "680564733841876926871408982642407768065",
+            life_support.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let ragged = PuzzleInput::new("report", "00100\n1111\n");
+        assert_eq!(
+            "report:2:1: expected 5 binary digits, got '1111'",
+            BinaryDiagnostic::parse(&ragged).unwrap_err().to_string()
+        );
+
+        let digit = PuzzleInput::new("report", "00100\n11210\n");
+        assert_eq!(
+            "report:2:3: expected binary digit, got '2'",
+            BinaryDiagnostic::parse(&digit).unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::TiePolicy;
+use shared::{option_value, MyError};
+
+/// Options of the day's own binary on top of the common ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Print the report instead of the two puzzle answers.
+    pub report: bool,
+    pub ties: TiePolicy,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            report: false,
+            ties: TiePolicy::PreferOne,
+        }
+    }
+}
+
+/// Takes `--report` and `--ties prefer-one|prefer-zero|error` out of `args`,
+/// returning the other arguments untouched. The tie policy applies to the
+/// answers as well as the report.
+pub fn parse_options<I: Iterator<Item = String>>(
+    mut args: I,
+) -> Result<(Options, Vec<String>), MyError> {
+    let mut options = Options::default();
+    let mut rest = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--report" => options.report = true,
+            "--ties" => options.ties = option_value(&mut args, "--ties")?.parse()?,
+            _ => rest.push(arg),
+        }
+    }
+    Ok((options, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_options() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        let (options, rest) = parse_options(args("- --format json").into_iter()).unwrap();
+        assert_eq!(Options::default(), options);
+        assert_eq!(vec!["-", "--format", "json"], rest);
+
+        let (options, _) = parse_options(args("--ties error -").into_iter()).unwrap();
+        let expected = Options {
+            report: false,
+            ties: TiePolicy::Error,
+        };
+        assert_eq!(expected, options);
+
+        let (options, _) = parse_options(args("--report -").into_iter()).unwrap();
+        assert!(options.report);
+        assert_eq!(TiePolicy::PreferOne, options.ties);
+    }
+}
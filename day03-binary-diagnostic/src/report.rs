@@ -0,0 +1,141 @@
+use crate::bits::Bits;
+use crate::{get_life_support_rate, Filter, LifeSupportRatingType, TiePolicy};
+use serde_json::{json, Value};
+use shared::MyError;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnCount {
+    pub ones: usize,
+    pub zeros: usize,
+}
+
+/// Bit counts per column and the steps that led to each life support rating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub columns: Vec<ColumnCount>,
+    pub oxygen: Filter,
+    pub co2: Filter,
+}
+
+impl Report {
+    pub fn new(readings: &[Bits], width: usize, ties: TiePolicy) -> Result<Self, MyError> {
+        let columns = (0..width)
+            .map(|col| {
+                let ones = readings.iter().filter(|r| r.get(col)).count();
+                ColumnCount {
+                    ones,
+                    zeros: readings.len() - ones,
+                }
+            })
+            .collect();
+
+        Ok(Report {
+            columns,
+            oxygen: get_life_support_rate(
+                readings,
+                width,
+                LifeSupportRatingType::MostCommon,
+                ties,
+            )?,
+            co2: get_life_support_rate(readings, width, LifeSupportRatingType::LeastCommon, ties)?,
+        })
+    }
+
+    pub fn to_json(&self) -> Value {
+        let columns = self
+            .columns
+            .iter()
+            .map(|count| json!({ "ones": count.ones, "zeros": count.zeros }))
+            .collect::<Vec<_>>();
+        json!({
+            "columns": columns,
+            "oxygen": filter_json(&self.oxygen),
+            "co2": filter_json(&self.co2),
+        })
+    }
+}
+
+fn filter_json(filter: &Filter) -> Value {
+    let steps = filter
+        .steps
+        .iter()
+        .map(|step| {
+            json!({
+                "column": step.column,
+                "ones": step.ones,
+                "zeros": step.zeros,
+                "kept": step.kept as u8,
+                "remaining": step.remaining,
+            })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "steps": steps,
+        "rating": filter.rating.to_string(),
+        "value": filter.rating.to_u64(),
+    })
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>6} {:>6} {:>6}", "column", "ones", "zeros")?;
+        for (col, count) in self.columns.iter().enumerate() {
+            writeln!(f, "{:>6} {:>6} {:>6}", col, count.ones, count.zeros)?;
+        }
+        write_filter(f, "Oxygen generator rating", &self.oxygen)?;
+        write_filter(f, "CO2 scrubber rating", &self.co2)
+    }
+}
+
+fn write_filter(f: &mut fmt::Formatter<'_>, name: &str, filter: &Filter) -> fmt::Result {
+    write!(f, "\n{}: {}", name, filter.rating)?;
+    match filter.rating.to_u64() {
+        Some(value) => writeln!(f, " ({})", value)?,
+        None => writeln!(f)?,
+    }
+    writeln!(
+        f,
+        "{:>6} {:>6} {:>6} {:>6} {:>9}",
+        "column", "ones", "zeros", "kept", "remaining"
+    )?;
+    for step in &filter.steps {
+        writeln!(
+            f,
+            "{:>6} {:>6} {:>6} {:>6} {:>9}",
+            step.column, step.ones, step.zeros, step.kept as u8, step.remaining
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BinaryDiagnostic;
+    use shared::{PuzzleInput, Solution};
+
+    #[test]
+    fn test_report() {
+        let input = PuzzleInput::new(
+            "example",
+            "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010",
+        );
+        let (readings, width) = BinaryDiagnostic::parse(&input).unwrap();
+
+        let report = Report::new(&readings, width, TiePolicy::PreferOne).unwrap();
+
+        assert_eq!(ColumnCount { ones: 7, zeros: 5 }, report.columns[0]);
+        let remaining =
+            |filter: &Filter| filter.steps.iter().map(|s| s.remaining).collect::<Vec<_>>();
+        assert_eq!(vec![7, 4, 3, 2, 1], remaining(&report.oxygen));
+        assert_eq!(vec![5, 2, 1], remaining(&report.co2));
+
+        let json = report.to_json();
+        assert_eq!("01010", json["co2"]["rating"]);
+        assert_eq!(23, json["oxygen"]["value"]);
+        assert!(report
+            .to_string()
+            .contains("CO2 scrubber rating: 01010 (10)\n"));
+    }
+}
@@ -0,0 +1,139 @@
+//! Plays a generated game of 100k boards with `simulate`, which only marks
+//! the boards holding each number and counts what is left of every line, and
+//! with the previous design, which offered every number to every board still
+//! in play, looked cells up in hash maps and rescanned each line through a
+//! marked cell.
+//!
+//! Run with `cargo bench -p day04-giant-squid`.
+
+use day04_giant_squid::board::{Board, STANDARD_RULES};
+use day04_giant_squid::{read_input, simulate};
+use shared::{Point, PuzzleInput};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const BOARDS: usize = 100_000;
+const SIZE: usize = 5;
+const NUMBERS: i32 = 1_000;
+const RUNS: usize = 3;
+
+fn main() {
+    let input = PuzzleInput::new("generated", &generate(BOARDS, 0x5eed));
+    let (boards, numbers) = read_input(&input, STANDARD_RULES).unwrap();
+    let reference = boards.iter().map(HashBoard::new).collect::<Vec<_>>();
+
+    let counted = fastest(|| simulate(boards.clone(), &numbers).wins.len());
+    let hashed = fastest(|| simulate_hashed(reference.clone(), &numbers));
+    println!(
+        "{} boards, {} draws\n{:<14} {:>10.1?}\n{:<14} {:>10.1?}\nspeed-up {:.1}x",
+        BOARDS,
+        numbers.len(),
+        "line counts",
+        counted,
+        "hash maps",
+        hashed,
+        hashed.as_secs_f64() / counted.as_secs_f64()
+    );
+}
+
+/// Quickest of `RUNS` runs of `f`, which returns how many boards won.
+fn fastest<F: FnMut() -> usize>(mut f: F) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+/// A shuffled draw of every number below `NUMBERS`, and `boards` boards of
+/// distinct numbers from the same range.
+fn generate(boards: usize, seed: u64) -> String {
+    let mut rng = XorShift(seed);
+    let mut numbers = (0..NUMBERS).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+    let mut game = numbers
+        .iter()
+        .map(|num| num.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    for _ in 0..boards {
+        rng.shuffle(&mut numbers);
+        game += "\n";
+        for row in numbers[..SIZE * SIZE].chunks(SIZE) {
+            let row = row.iter().map(|num| format!("{:>3}", num));
+            game += &format!("\n{}", row.collect::<Vec<_>>().join(" "));
+        }
+    }
+    game
+}
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, (self.next() % (idx as u64 + 1)) as usize);
+        }
+    }
+}
+
+#[derive(Clone)]
+struct HashBoard {
+    numbers: HashMap<i32, Point>,
+    marked: HashMap<Point, bool>,
+    lines: Vec<Vec<Point>>,
+}
+
+impl HashBoard {
+    fn new(board: &Board) -> Self {
+        let mut numbers = HashMap::new();
+        let mut marked = HashMap::new();
+        for (row, cells) in board.rows().into_iter().enumerate() {
+            for (col, (num, _)) in cells.into_iter().enumerate() {
+                numbers.insert(num, (row, col));
+                marked.insert((row, col), false);
+            }
+        }
+        HashBoard {
+            numbers,
+            marked,
+            lines: board.lines().to_vec(),
+        }
+    }
+
+    fn mark(&mut self, num: i32) -> bool {
+        let pos = match self.numbers.get(&num) {
+            Some(&pos) => pos,
+            None => return false,
+        };
+        self.marked.insert(pos, true);
+        self.lines
+            .iter()
+            .filter(|line| line.contains(&pos))
+            .any(|line| line.iter().all(|pos| self.marked[pos]))
+    }
+}
+
+fn simulate_hashed(mut playing: Vec<HashBoard>, numbers: &[i32]) -> usize {
+    let mut wins = 0;
+    for &num in numbers {
+        playing.retain_mut(|board| {
+            let won = board.mark(num);
+            wins += won as usize;
+            !won
+        });
+    }
+    wins
+}
@@ -1,80 +1,305 @@
-use std::collections::HashMap;
+use shared::{MyError, Point};
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::str::FromStr;
 
+/// A way of winning, described by the groups of cells that win together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WinRule {
+    Rows,
+    Columns,
+    /// Both diagonals; only on square boards.
+    Diagonals,
+    Corners,
+    FullCard,
+}
+
+/// The rules of the puzzle itself.
+pub const STANDARD_RULES: &[WinRule] = &[WinRule::Rows, WinRule::Columns];
+
+impl WinRule {
+    /// Lines of cells that win under this rule on a `rows` by `cols` board;
+    /// none for diagonals unless the board is square.
+    pub fn lines(self, rows: usize, cols: usize) -> Vec<Vec<Point>> {
+        match self {
+            WinRule::Rows => (0..rows)
+                .map(|row| (0..cols).map(|col| (row, col)).collect())
+                .collect(),
+            WinRule::Columns => (0..cols)
+                .map(|col| (0..rows).map(|row| (row, col)).collect())
+                .collect(),
+            WinRule::Diagonals if rows == cols => vec![
+                (0..rows).map(|idx| (idx, idx)).collect(),
+                (0..rows).map(|idx| (idx, cols - 1 - idx)).collect(),
+            ],
+            WinRule::Diagonals => vec![],
+            WinRule::Corners => {
+                let mut corners = vec![(0, 0), (0, cols - 1), (rows - 1, 0), (rows - 1, cols - 1)];
+                corners.sort_unstable();
+                corners.dedup();
+                vec![corners]
+            }
+            WinRule::FullCard => vec![(0..rows)
+                .flat_map(|row| (0..cols).map(move |col| (row, col)))
+                .collect()],
+        }
+    }
+}
+
+impl FromStr for WinRule {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rows" => Ok(WinRule::Rows),
+            "columns" => Ok(WinRule::Columns),
+            "diagonals" => Ok(WinRule::Diagonals),
+            "corners" => Ok(WinRule::Corners),
+            "full" => Ok(WinRule::FullCard),
+            other => Err(MyError::InvalidArgument(format!(
+                "Unknown win rule '{}', expected rows, columns, diagonals, corners or full",
+                other
+            ))),
+        }
+    }
+}
+
+/// Parses a comma-separated list of rules such as `rows,diagonals`.
+pub fn parse_rules(s: &str) -> Result<Vec<WinRule>, MyError> {
+    s.split(',').map(|rule| rule.trim().parse()).collect()
+}
+
+/// The winning lines of one board size under one set of rules, shared by
+/// every board of that size.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Layout {
+    cols: usize,
+    lines: Vec<Vec<Point>>,
+    /// Indices into `lines` of the lines through each cell, row by row.
+    cell_lines: Vec<Vec<usize>>,
+}
+
+impl Layout {
+    /// Fails if the rules ask for diagonals on a board that isn't square.
+    pub fn new(rows: usize, cols: usize, rules: &[WinRule]) -> Result<Self, MyError> {
+        if rows != cols && rules.contains(&WinRule::Diagonals) {
+            return Err(MyError::InvalidArgument(format!(
+                "Win rule 'diagonals' needs square boards, got a {}x{} board",
+                rows, cols
+            )));
+        }
+        let lines = rules
+            .iter()
+            .flat_map(|rule| rule.lines(rows, cols))
+            .collect::<Vec<_>>();
+        let mut cell_lines = vec![vec![]; rows * cols];
+        for (idx, line) in lines.iter().enumerate() {
+            for &(row, col) in line {
+                cell_lines[row * cols + col].push(idx);
+            }
+        }
+        Ok(Layout {
+            cols,
+            lines,
+            cell_lines,
+        })
+    }
+}
+
+/// Cells are kept row by row, with a count of unmarked cells per line so
+/// that marking a number only touches the lines through its cell.
 #[derive(Debug, Clone)]
 pub struct Board {
-    numbers: HashMap<i32, (usize, usize)>,
-    marked: HashMap<(usize, usize), (i32, bool)>,
+    layout: Rc<Layout>,
+    cells: Vec<(i32, bool)>,
+    /// Numbers on the board with their cell, sorted by number.
+    index: Vec<(i32, usize)>,
+    remaining: Vec<usize>,
     pub unmarked_sum: i32,
-    cols: usize,
-    rows: usize,
 }
 
 impl Board {
-    pub fn new() -> Self {
+    /// `rows` must be non-empty and all the same length.
+    pub fn new(rows: Vec<Vec<i32>>, rules: &[WinRule]) -> Result<Self, MyError> {
+        let layout = Layout::new(rows.len(), rows[0].len(), rules)?;
+        Ok(Board::with_layout(rows, Rc::new(layout)))
+    }
+
+    /// `rows` must match the size of `layout`.
+    pub fn with_layout(rows: Vec<Vec<i32>>, layout: Rc<Layout>) -> Self {
+        let cells = rows
+            .into_iter()
+            .flatten()
+            .map(|num| (num, false))
+            .collect::<Vec<_>>();
+        let mut index = cells
+            .iter()
+            .enumerate()
+            .map(|(cell, &(num, _))| (num, cell))
+            .collect::<Vec<_>>();
+        index.sort_unstable();
+
         Board {
-            numbers: HashMap::new(),
-            marked: HashMap::new(),
-            unmarked_sum: 0,
-            cols: 5,
-            rows: 5,
+            remaining: layout.lines.iter().map(Vec::len).collect(),
+            unmarked_sum: cells.iter().map(|cell| cell.0).sum(),
+            layout,
+            cells,
+            index,
         }
     }
 
-    pub fn add_row(&mut self, row_items: Vec<i32>, row: usize) {
-        for col in 0..self.cols {
-            self.add_number(row_items[col], row, col)
-        }
+    pub fn cols(&self) -> usize {
+        self.layout.cols
     }
 
-    pub fn add_number(&mut self, num: i32, row: usize, col: usize) {
-        let pos = (col, row);
-        self.numbers.insert(num, pos);
-        self.marked.insert(pos, (num, false));
-        self.unmarked_sum += num;
+    /// Every cell's number and whether it has been marked, row by row.
+    pub fn rows(&self) -> Vec<Vec<(i32, bool)>> {
+        self.cells
+            .chunks(self.layout.cols)
+            .map(<[_]>::to_vec)
+            .collect()
     }
 
+    /// Every cell's position and number, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, i32)> + '_ {
+        let cols = self.layout.cols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(cell, &(num, _))| ((cell / cols, cell % cols), num))
+    }
+
+    /// Every group of cells that wins the board once marked.
+    pub fn lines(&self) -> &[Vec<Point>] {
+        &self.layout.lines
+    }
+
+    /// Marks every cell holding `num` and returns whether that completed a
+    /// line through one of them. Finding the cells is a binary search of the
+    /// board's own numbers; callers marking many boards should keep a table
+    /// of positions and use `mark_at`, as `Game` does.
     pub fn mark(&mut self, num: i32) -> bool {
-        if !self.numbers.contains_key(&num) {
+        let start = self.index.partition_point(|&(n, _)| n < num);
+        let mut completed = false;
+        for idx in start..self.index.len() {
+            let (n, cell) = self.index[idx];
+            if n != num {
+                break;
+            }
+            completed |= self.mark_cell(cell);
+        }
+        completed
+    }
+
+    /// Marks the cell at `pos` and returns whether that completed a line
+    /// through it. Marking a cell twice has no effect.
+    pub fn mark_at(&mut self, pos: Point) -> bool {
+        self.mark_cell(self.cell(pos))
+    }
+
+    fn mark_cell(&mut self, cell: usize) -> bool {
+        if self.cells[cell].1 {
             return false;
         }
+        self.cells[cell].1 = true;
+        self.unmarked_sum -= self.cells[cell].0;
 
-        let pos = self.numbers[&num];
-        self.marked.insert(pos, (num, true));
-        self.unmarked_sum -= num;
+        let mut completed = false;
+        for &line in &self.layout.cell_lines[cell] {
+            self.remaining[line] -= 1;
+            completed |= self.remaining[line] == 0;
+        }
+        completed
+    }
 
-        self.has_won(pos.0, pos.1)
+    pub fn is_complete(&self, line: &[Point]) -> bool {
+        line.iter().all(|&pos| self.cells[self.cell(pos)].1)
     }
 
-    fn has_won(&self, col: usize, row: usize) -> bool {
-        let mut row_marked = true;
-        for i in 0..self.cols {
-            if !self.marked[&(i, row)].1 {
-                row_marked = false;
-            }
-        }
-        let mut col_marked = true;
-        for j in 0..self.rows {
-            if !self.marked[&(col, j)].1 {
-                col_marked = false;
+    pub fn number_at(&self, pos: Point) -> i32 {
+        self.cells[self.cell(pos)].0
+    }
+
+    /// Where `num` is on the board, if anywhere.
+    pub fn position(&self, num: i32) -> Option<Point> {
+        let start = self.index.partition_point(|&(n, _)| n < num);
+        match self.index.get(start) {
+            Some(&(n, cell)) if n == num => {
+                Some((cell / self.layout.cols, cell % self.layout.cols))
             }
+            _ => None,
         }
-        row_marked || col_marked
+    }
+
+    /// Cells that belong to at least one completed line.
+    pub fn completed_cells(&self) -> HashSet<Point> {
+        self.layout
+            .lines
+            .iter()
+            .zip(&self.remaining)
+            .filter(|&(_, &remaining)| remaining == 0)
+            .flat_map(|(line, _)| line)
+            .copied()
+            .collect()
+    }
+
+    fn cell(&self, (row, col): Point) -> usize {
+        row * self.layout.cols + col
     }
 }
 
 impl std::fmt::Display for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut result = String::new();
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                result += &format!(
-                    "{:>4}{}",
-                    self.marked[&(col, row)].0,
-                    if self.marked[&(col, row)].1 { "*" } else { " " },
-                );
+        for row in self.cells.chunks(self.layout.cols) {
+            for &(num, marked) in row {
+                result += &format!("{:>4}{}", num, if marked { "*" } else { " " });
             }
             result += "\n";
         }
         write!(f, "{}", result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark() {
+        let mut board = Board::new(vec![vec![1, 2, 3], vec![4, 5, 6]], STANDARD_RULES).unwrap();
+
+        assert!(!board.mark(2));
+        assert!(!board.mark(7));
+        assert!(!board.mark(2));
+        assert_eq!(19, board.unmarked_sum);
+        assert!(board.mark(5));
+        assert_eq!(Some((1, 1)), board.position(5));
+        assert_eq!(HashSet::from([(0, 1), (1, 1)]), board.completed_cells());
+        assert_eq!("   1    2*   3 \n   4    5*   6 \n", board.to_string());
+    }
+
+    #[test]
+    fn test_shared_layout() {
+        let layout = Rc::new(Layout::new(2, 2, &[WinRule::Diagonals]).unwrap());
+        let mut first = Board::with_layout(vec![vec![1, 2], vec![3, 4]], layout.clone());
+        let mut second = Board::with_layout(vec![vec![4, 3], vec![2, 1]], layout);
+
+        assert!(!first.mark(1) && !second.mark(1));
+        assert!(first.mark(4));
+        assert!(second.mark_at((0, 0)));
+        assert!(!second.mark_at((0, 0)));
+        assert_eq!(5, second.unmarked_sum);
+        assert_eq!(2, first.lines().len());
+    }
+
+    #[test]
+    fn test_diagonals_need_square_boards() {
+        let err = Layout::new(2, 3, &[WinRule::Rows, WinRule::Diagonals]).unwrap_err();
+        assert_eq!(
+            "Win rule 'diagonals' needs square boards, got a 2x3 board",
+            err.to_string()
+        );
+        assert!(Layout::new(2, 3, STANDARD_RULES).is_ok());
+    }
+}
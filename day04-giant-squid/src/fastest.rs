@@ -0,0 +1,177 @@
+use crate::board::Board;
+use serde_json::{json, Value};
+use shared::MyError;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// The quickest way a board can win on its own, ignoring the other boards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fastest {
+    pub board_index: usize,
+    /// Fewest numbers that have to be drawn before the board wins.
+    pub draws: usize,
+    /// Numbers of the shortest line, in line order.
+    pub numbers: Vec<i32>,
+}
+
+/// The shortest line of every board; the first one wins ties.
+pub fn fastest_wins(boards: &[Board]) -> Vec<Fastest> {
+    boards
+        .iter()
+        .enumerate()
+        .filter_map(|(board_index, board)| {
+            let numbers = line_numbers(board).min_by_key(Vec::len)?;
+            Some(Fastest {
+                board_index,
+                draws: numbers.len(),
+                numbers,
+            })
+        })
+        .collect()
+}
+
+/// A draw in which board `target` wins alone before any other board. It
+/// starts with the numbers of the shortest line that no other board can
+/// complete from, followed by every other number on the boards in ascending
+/// order so that the rest of the game still plays out.
+pub fn rig_draw(boards: &[Board], target: usize) -> Result<Vec<i32>, MyError> {
+    let board = boards.get(target).ok_or_else(|| {
+        MyError::InvalidArgument(format!(
+            "Board {} doesn't exist, there are {} boards",
+            target,
+            boards.len()
+        ))
+    })?;
+
+    let mut candidates = line_numbers(board).collect::<Vec<_>>();
+    candidates.sort_by_key(Vec::len);
+    let winning = candidates
+        .into_iter()
+        .find(|numbers| {
+            let drawn = numbers.iter().collect::<BTreeSet<_>>();
+            boards
+                .iter()
+                .enumerate()
+                .filter(|&(idx, _)| idx != target)
+                .all(|(_, other)| {
+                    !line_numbers(other).any(|line| line.iter().all(|num| drawn.contains(num)))
+                })
+        })
+        .ok_or_else(|| {
+            MyError::NoAnswer(format!(
+                "every line of board {} also completes another board",
+                target
+            ))
+        })?;
+
+    let rest = boards
+        .iter()
+        .flat_map(|board| board.rows().into_iter().flatten().map(|cell| cell.0))
+        .filter(|num| !winning.contains(num))
+        .collect::<BTreeSet<_>>();
+    Ok(winning.into_iter().chain(rest).collect())
+}
+
+/// Distinct numbers of every line, in line order.
+fn line_numbers(board: &Board) -> impl Iterator<Item = Vec<i32>> + '_ {
+    board.lines().iter().map(move |line| {
+        let mut seen = BTreeSet::new();
+        line.iter()
+            .map(|&pos| board.number_at(pos))
+            .filter(|&num| seen.insert(num))
+            .collect()
+    })
+}
+
+pub fn to_json(fastest: &[Fastest]) -> Value {
+    let boards = fastest
+        .iter()
+        .map(|board| {
+            json!({
+                "board_index": board.board_index,
+                "draws": board.draws,
+                "numbers": board.numbers,
+            })
+        })
+        .collect::<Vec<_>>();
+    Value::Array(boards)
+}
+
+impl fmt::Display for Fastest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers = self
+            .numbers
+            .iter()
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "Board {}: {} draws ({})",
+            self.board_index,
+            self.draws,
+            numbers.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::STANDARD_RULES;
+    use crate::{read_input, simulate, GiantSquid};
+    use shared::{PuzzleInput, Solution};
+
+    #[test]
+    fn test_fastest_wins() {
+        let input = PuzzleInput::from_file("test-input.txt").unwrap();
+        let (boards, _) = GiantSquid::parse(&input).unwrap();
+
+        let fastest = fastest_wins(&boards);
+
+        assert_eq!(3, fastest.len());
+        assert_eq!(vec![22, 13, 17, 11, 0], fastest[0].numbers);
+        assert!(fastest.iter().all(|board| board.draws == 5));
+        assert_eq!("Board 0: 5 draws (22,13,17,11,0)", fastest[0].to_string());
+    }
+
+    #[test]
+    fn test_rig_draw() {
+        let input = PuzzleInput::from_file("test-input.txt").unwrap();
+        let (boards, _) = GiantSquid::parse(&input).unwrap();
+
+        for target in 0..boards.len() {
+            let draw = rig_draw(&boards, target).unwrap();
+            let simulation = simulate(boards.clone(), &draw);
+            let first = simulation.first().unwrap();
+            assert_eq!((target, 4), (first.board_index, first.draw_index));
+            assert!(simulation.never_won.is_empty());
+        }
+        assert!(rig_draw(&boards, 3).is_err());
+    }
+
+    #[test]
+    fn test_repeated_numbers() {
+        let game = "1\n\n1 1\n2 3\n";
+        let (boards, _) = read_input(&PuzzleInput::new("game", game), STANDARD_RULES).unwrap();
+
+        assert_eq!("Board 0: 1 draws (1)", fastest_wins(&boards)[0].to_string());
+        assert_eq!(vec![1, 2, 3], rig_draw(&boards, 0).unwrap());
+    }
+
+    #[test]
+    fn test_rig_draw_skips_shared_lines() {
+        let game = "1\n\n1 2\n3 4\n\n1 2\n5 6\n\n7 8\n9 1\n";
+        let (boards, _) = read_input(&PuzzleInput::new("game", game), STANDARD_RULES).unwrap();
+
+        assert_eq!(
+            vec![3, 4, 1, 2, 5, 6, 7, 8, 9],
+            rig_draw(&boards, 0).unwrap()
+        );
+        let shared = "1\n\n1 2\n\n1 2\n";
+        let (boards, _) = read_input(&PuzzleInput::new("game", shared), STANDARD_RULES).unwrap();
+        assert_eq!(
+            "No answer: every line of board 0 also completes another board",
+            rig_draw(&boards, 0).unwrap_err().to_string()
+        );
+    }
+}
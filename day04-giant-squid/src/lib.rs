@@ -0,0 +1,380 @@
+pub mod board;
+pub mod fastest;
+pub mod options;
+pub mod replay;
+
+use board::{Board, Layout, WinRule, STANDARD_RULES};
+use serde_json::{json, Value};
+use shared::{Answer, MyError, Point, PuzzleInput, Solution};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+pub struct GiantSquid;
+
+impl Solution for GiantSquid {
+    const DAY: u8 = 4;
+
+    type Input = (Vec<Board>, Vec<i32>);
+    type Answer1 = Win;
+    type Answer2 = Win;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        read_input(input, STANDARD_RULES)
+    }
+
+    fn part1((boards, numbers): &Self::Input) -> Result<Self::Answer1, MyError> {
+        simulate(boards.to_vec(), numbers).first().cloned()
+    }
+
+    fn part2((boards, numbers): &Self::Input) -> Result<Self::Answer2, MyError> {
+        simulate(boards.to_vec(), numbers).last().cloned()
+    }
+}
+
+/// A board at the moment it won, together with the number that completed it.
+#[derive(Debug, Clone)]
+pub struct Win {
+    pub board_index: usize,
+    /// Position of `number` in the draw.
+    pub draw_index: usize,
+    pub number: i32,
+    pub board: Board,
+}
+
+impl Win {
+    pub fn score(&self) -> i32 {
+        self.number * self.board.unmarked_sum
+    }
+}
+
+impl fmt::Display for Win {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.score())
+    }
+}
+
+impl Answer for Win {
+    fn details(&self) -> Option<Value> {
+        let rows = self.board.rows();
+        let numbers = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.0).collect::<Vec<_>>());
+        let marked = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.1).collect::<Vec<_>>());
+
+        Some(json!({
+            "board_index": self.board_index,
+            "draw_index": self.draw_index,
+            "winning_number": self.number,
+            "unmarked_sum": self.board.unmarked_sum,
+            "numbers": numbers.collect::<Vec<_>>(),
+            "marked": marked.collect::<Vec<_>>(),
+        }))
+    }
+}
+
+/// Every board's result from playing the whole draw once.
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    /// Boards in the order they won; boards completing on the same draw are
+    /// in index order.
+    pub wins: Vec<Win>,
+    /// Indices of the boards still without a line after the last draw.
+    pub never_won: Vec<usize>,
+}
+
+impl Simulation {
+    pub fn first(&self) -> Result<&Win, MyError> {
+        self.wins
+            .first()
+            .ok_or_else(|| MyError::NoAnswer("no board wins".to_string()))
+    }
+
+    pub fn last(&self) -> Result<&Win, MyError> {
+        if !self.never_won.is_empty() {
+            return Err(MyError::NoAnswer(format!(
+                "boards {:?} never win",
+                self.never_won
+            )));
+        }
+        self.wins
+            .last()
+            .ok_or_else(|| MyError::NoAnswer("no board wins".to_string()))
+    }
+
+    pub fn to_json(&self) -> Value {
+        let wins = self
+            .wins
+            .iter()
+            .enumerate()
+            .map(|(rank, win)| {
+                json!({
+                    "rank": rank + 1,
+                    "board_index": win.board_index,
+                    "draw_index": win.draw_index,
+                    "winning_number": win.number,
+                    "score": win.score(),
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({ "wins": wins, "never_won": self.never_won })
+    }
+}
+
+impl fmt::Display for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5} {:>6} {:>5} {:>7} {:>8}",
+            "rank", "board", "draw", "number", "score"
+        )?;
+        for (rank, win) in self.wins.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>5} {:>6} {:>5} {:>7} {:>8}",
+                rank + 1,
+                win.board_index,
+                win.draw_index,
+                win.number,
+                win.score()
+            )?;
+        }
+        if !self.never_won.is_empty() {
+            let boards = self
+                .never_won
+                .iter()
+                .map(|idx| idx.to_string())
+                .collect::<Vec<_>>();
+            writeln!(f, "Never won: {}", boards.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Boards in play, marked one draw at a time. Boards stop being marked once
+/// they win. A table of where every number is on every board means a draw
+/// only visits the cells holding it, each of which is marked in constant
+/// time.
+#[derive(Debug, Clone)]
+pub struct Game {
+    boards: Vec<Board>,
+    /// Board index and position of every cell, by number.
+    holding: HashMap<i32, Vec<(usize, Point)>>,
+    won_at: Vec<Option<usize>>,
+    playing: usize,
+}
+
+impl Game {
+    pub fn new(boards: Vec<Board>) -> Self {
+        let mut holding: HashMap<i32, Vec<(usize, Point)>> = HashMap::new();
+        for (board_index, board) in boards.iter().enumerate() {
+            for (pos, num) in board.cells() {
+                holding.entry(num).or_default().push((board_index, pos));
+            }
+        }
+
+        Game {
+            won_at: vec![None; boards.len()],
+            playing: boards.len(),
+            boards,
+            holding,
+        }
+    }
+
+    /// Marks `num`, drawn at `draw_index`, and returns the boards it won in
+    /// index order.
+    pub fn draw(&mut self, draw_index: usize, num: i32) -> Vec<usize> {
+        let mut won = vec![];
+        for &(board_index, pos) in self.holding.get(&num).into_iter().flatten() {
+            if self.won_at[board_index].is_none() && self.boards[board_index].mark_at(pos) {
+                self.won_at[board_index] = Some(draw_index);
+                won.push(board_index);
+            }
+        }
+        self.playing -= won.len();
+        won
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    /// The draw index at which board `board_index` won, if it has.
+    pub fn won_at(&self, board_index: usize) -> Option<usize> {
+        self.won_at[board_index]
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.playing == 0
+    }
+}
+
+/// Plays every number in order, taking each board out of play once it wins.
+pub fn simulate(boards: Vec<Board>, numbers: &[i32]) -> Simulation {
+    let mut game = Game::new(boards);
+    let mut wins = vec![];
+    for (draw_index, &num) in numbers.iter().enumerate() {
+        for board_index in game.draw(draw_index, num) {
+            wins.push(Win {
+                board_index,
+                draw_index,
+                number: num,
+                board: game.boards()[board_index].clone(),
+            });
+        }
+        if game.is_over() {
+            break;
+        }
+    }
+
+    Simulation {
+        wins,
+        never_won: (0..game.boards().len())
+            .filter(|&idx| game.won_at(idx).is_none())
+            .collect(),
+    }
+}
+
+/// Reads the drawn numbers and the boards, each of which takes its size from
+/// its own rows. Boards of the same size share their layout.
+pub fn read_input(
+    input: &PuzzleInput,
+    rules: &[WinRule],
+) -> Result<(Vec<Board>, Vec<i32>), MyError> {
+    let mut lines = input.lines();
+
+    let first_line = lines
+        .next()
+        .ok_or_else(|| input.unexpected_end("drawn numbers"))?;
+    let numbers: Vec<i32> = first_line.parse_list(first_line.text.trim(), ",", "integer")?;
+
+    let mut layouts = HashMap::new();
+    let mut board = |rows: Vec<Vec<i32>>| -> Result<Board, MyError> {
+        let size = (rows.len(), rows[0].len());
+        let layout = match layouts.entry(size) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Rc::new(Layout::new(size.0, size.1, rules)?)),
+        };
+        Ok(Board::with_layout(rows, layout.clone()))
+    };
+
+    let mut boards: Vec<Board> = vec![];
+    let mut rows: Vec<Vec<i32>> = vec![];
+    for line in lines {
+        if line.text.trim().is_empty() {
+            if !rows.is_empty() {
+                boards.push(board(std::mem::take(&mut rows))?);
+            }
+            continue;
+        }
+
+        let row_items = line
+            .text
+            .split_whitespace()
+            .map(|s| line.parse(s, "integer"))
+            .collect::<Result<Vec<i32>, _>>()?;
+        if let Some(first) = rows.first() {
+            if row_items.len() != first.len() {
+                let expected = format!("row of {} numbers", first.len());
+                return Err(line.error(line.text, &expected));
+            }
+        }
+        rows.push(row_items);
+    }
+    if !rows.is_empty() {
+        boards.push(board(rows)?);
+    }
+    Ok((boards, numbers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_first_and_last_to_win() {
+        let input = PuzzleInput::from_file("test-input.txt").unwrap();
+        let game = GiantSquid::parse(&input).unwrap();
+
+        let first = GiantSquid::part1(&game).unwrap();
+        assert_eq!(
+            (2, 24, 4512),
+            (first.board_index, first.number, first.score())
+        );
+        let last = GiantSquid::part2(&game).unwrap();
+        assert_eq!((1, 13, 1924), (last.board_index, last.number, last.score()));
+    }
+
+    fn play(text: &str, rules: &str) -> Result<Win, MyError> {
+        let input = PuzzleInput::new("game", text);
+        let (boards, numbers) = read_input(&input, &board::parse_rules(rules)?)?;
+        simulate(boards, &numbers).first().cloned()
+    }
+
+    #[test]
+    fn test_simulate_ranks_every_board() {
+        let input = PuzzleInput::from_file("test-input.txt").unwrap();
+        let (boards, numbers) = GiantSquid::parse(&input).unwrap();
+
+        let simulation = simulate(boards, &numbers);
+
+        let ranking = simulation
+            .wins
+            .iter()
+            .map(|win| (win.board_index, win.draw_index, win.score()))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(2, 11, 4512), (0, 13, 2192), (1, 14, 1924)], ranking);
+        assert!(simulation.never_won.is_empty());
+    }
+
+    #[test]
+    fn test_boards_that_never_win() {
+        let game = "1,2,3\n\n1 2\n3 4\n\n5 6\n7 8\n";
+        let (boards, numbers) =
+            read_input(&PuzzleInput::new("game", game), STANDARD_RULES).unwrap();
+
+        let simulation = simulate(boards, &numbers);
+
+        assert_eq!(vec![1], simulation.never_won);
+        assert_eq!(2, simulation.first().unwrap().number);
+        assert_eq!(
+            "No answer: boards [1] never win",
+            simulation.last().unwrap_err().to_string()
+        );
+        assert!(simulation.to_string().ends_with("Never won: 1\n"));
+    }
+
+    #[test]
+    fn test_board_size_from_input() {
+        let game = "5,1,9\n\n1 2 3\n4 5 6\n7 8 9\n\n\n1 2\n3 4\n5 9\n";
+        let (boards, _) = read_input(&PuzzleInput::new("game", game), STANDARD_RULES).unwrap();
+
+        assert_eq!(2, boards.len());
+        assert_eq!((3, 3), (boards[0].rows().len(), boards[0].cols()));
+        assert_eq!((3, 2), (boards[1].rows().len(), boards[1].cols()));
+
+        let ragged = PuzzleInput::new("game", "1\n\n1 2 3\n4 5\n");
+        assert_eq!(
+            "game:4:1: expected row of 3 numbers, got '4 5'",
+            read_input(&ragged, STANDARD_RULES).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_win_rules() {
+        let board = "\n1 2 3\n4 5 6\n7 8 9\n";
+        let game = |draws: &str| format!("{}\n{}", draws, board);
+
+        assert!(play(&game("1,5,9"), "rows,columns").is_err());
+        assert_eq!(9, play(&game("1,5,9"), "diagonals").unwrap().number);
+        assert_eq!(7, play(&game("3,5,7"), "rows,diagonals").unwrap().number);
+        assert_eq!(9, play(&game("1,3,7,9"), "corners").unwrap().number);
+        assert!(play(&game("1,2,3,4,5,6,7,8"), "full").is_err());
+        assert_eq!(0, play(&game("1,2,3,4,5,6,7,8,9"), "full").unwrap().score());
+        assert!(board::parse_rules("rows,stars").is_err());
+        assert!(play("1,2\n\n1 2\n3 4\n\n1 2 3\n4 5 6\n", "diagonals").is_err());
+    }
+}
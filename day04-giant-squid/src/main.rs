@@ -1,84 +1,51 @@
-mod board;
-use board::Board;
-use shared::{file_lines, read_first_arg, MyError};
+use day04_giant_squid::fastest::{self, fastest_wins, rig_draw};
+use day04_giant_squid::options::{parse_options, Mode};
+use day04_giant_squid::replay::replay;
+use day04_giant_squid::{read_input, simulate, GiantSquid};
+use shared::{parse_main_args, print_results, Format, MyError, PartResult, Solution};
 
 fn main() -> Result<(), MyError> {
-    let input = read_first_arg()?;
-
-    let (boards, numbers) = read_input(&input)?;
-
-    pick_first_to_win(boards.iter().cloned().collect(), numbers.clone())?;
-    pick_last_to_win(boards, numbers)?;
-
-    Ok(())
-}
-
-fn pick_first_to_win(mut boards: Vec<Board>, numbers: Vec<i32>) -> Result<(), MyError> {
-    for num in numbers {
-        print!("{} ", num);
-        for i in 0..boards.len() {
-            if boards[i].mark(num) {
-                println!();
-                println!("First board to win:");
-                println!("{}", boards[i]);
-                println!("Score = {}", num * boards[i].unmarked_sum);
-                return Ok(());
+    let (options, rest) = parse_options(std::env::args().skip(1))?;
+    let args = parse_main_args(rest.into_iter())?;
+    let game = read_input(&args.input.read()?, &options.rules)?;
+
+    match options.mode {
+        Mode::Answers => {
+            let results = vec![
+                PartResult::new(GiantSquid::DAY, 1, GiantSquid::part1(&game)),
+                PartResult::new(GiantSquid::DAY, 2, GiantSquid::part2(&game)),
+            ];
+            print_results(results, args.format)
+        }
+        Mode::Rank => {
+            let (boards, numbers) = game;
+            let simulation = simulate(boards, &numbers);
+            match args.format {
+                Format::Text => print!("{}", simulation),
+                Format::Json => println!("{}", simulation.to_json()),
             }
+            Ok(())
         }
-    }
-    Ok(())
-}
-
-fn pick_last_to_win(mut boards: Vec<Board>, numbers: Vec<i32>) -> Result<(), MyError> {
-    for num in numbers {
-        print!("{} ", num);
-        for i in (0..boards.len()).rev() {
-            if boards[i].mark(num) {
-                if boards.len() == 1 {
-                    println!();
-                    println!("Last board to win:");
-                    println!("{}", boards[i]);
-                    println!("Score = {}", num * boards[i].unmarked_sum);
-                    return Ok(());
-                }
-                boards.remove(i);
+        Mode::Replay => {
+            let (boards, numbers) = game;
+            Ok(replay(boards, &numbers, options.delay)?)
+        }
+        Mode::Fastest => {
+            let fastest = fastest_wins(&game.0);
+            match args.format {
+                Format::Text => fastest.iter().for_each(|board| println!("{}", board)),
+                Format::Json => println!("{}", fastest::to_json(&fastest)),
             }
+            Ok(())
         }
-    }
-    Ok(())
-}
-
-fn read_input(file_path: &str) -> Result<(Vec<Board>, Vec<i32>), MyError> {
-    let mut lines = file_lines(file_path)?;
-
-    let numbers: Vec<i32> = lines
-        .next()
-        .unwrap()
-        .unwrap()
-        .split(",")
-        .map(|s| s.parse().unwrap())
-        .collect();
-    lines.next();
-
-    let mut boards: Vec<Board> = vec![];
-    let mut board = Board::new();
-    let mut row = 0;
-    for line in lines {
-        let line = line.unwrap();
-        if line.is_empty() {
-            boards.push(board);
-            row = 0;
-            board = Board::new();
-        } else {
-            let row_items: Vec<i32> = line
-                .split(" ")
-                .filter(|s| !s.is_empty())
-                .map(|s| s.trim().parse().unwrap())
-                .collect();
-            board.add_row(row_items, row);
-            row += 1;
+        Mode::Rig(board) => {
+            let draw = rig_draw(&game.0, board)?;
+            let numbers = draw.iter().map(|num| num.to_string()).collect::<Vec<_>>();
+            match args.format {
+                Format::Text => println!("{}", numbers.join(",")),
+                Format::Json => println!("{}", serde_json::json!(draw)),
+            }
+            Ok(())
         }
     }
-    boards.push(board);
-    Ok((boards, numbers))
 }
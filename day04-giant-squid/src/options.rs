@@ -0,0 +1,105 @@
+use crate::board::{parse_rules, WinRule, STANDARD_RULES};
+use shared::{option_value, MyError};
+use std::time::Duration;
+
+/// What the binary prints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Answers,
+    Rank,
+    Replay,
+    Fastest,
+    /// Print a draw in which the given board wins first.
+    Rig(usize),
+}
+
+/// Options of the day's own binary on top of the common ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub rules: Vec<WinRule>,
+    pub mode: Mode,
+    /// Pause between draws in a replay.
+    pub delay: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            rules: STANDARD_RULES.to_vec(),
+            mode: Mode::Answers,
+            delay: Duration::from_millis(250),
+        }
+    }
+}
+
+/// Takes `--win <rule,...>`, `--rank`, `--replay`, `--speed <ms>`,
+/// `--fastest` and `--rig <board>` out of `args`, returning the other
+/// arguments untouched.
+pub fn parse_options<I: Iterator<Item = String>>(
+    mut args: I,
+) -> Result<(Options, Vec<String>), MyError> {
+    let mut options = Options::default();
+    let mut rest = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--win" => options.rules = parse_rules(&option_value(&mut args, "--win")?)?,
+            "--rank" => options.mode = Mode::Rank,
+            "--replay" => options.mode = Mode::Replay,
+            "--fastest" => options.mode = Mode::Fastest,
+            "--rig" => {
+                let value = option_value(&mut args, "--rig")?;
+                let board = value.parse().map_err(|_| {
+                    MyError::InvalidArgument(format!(
+                        "Invalid board '{}', expected a board index",
+                        value
+                    ))
+                })?;
+                options.mode = Mode::Rig(board);
+            }
+            "--speed" => {
+                let value = option_value(&mut args, "--speed")?;
+                let millis = value.parse().map_err(|_| {
+                    MyError::InvalidArgument(format!(
+                        "Invalid speed '{}', expected milliseconds",
+                        value
+                    ))
+                })?;
+                options.delay = Duration::from_millis(millis);
+            }
+            _ => rest.push(arg),
+        }
+    }
+    Ok((options, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_options() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        let (options, rest) = parse_options(args("- --format json").into_iter()).unwrap();
+        assert_eq!(Options::default(), options);
+        assert_eq!(vec!["-", "--format", "json"], rest);
+
+        let (options, _) = parse_options(args("--win rows,corners -").into_iter()).unwrap();
+        assert_eq!(vec![WinRule::Rows, WinRule::Corners], options.rules);
+        assert_eq!(Mode::Answers, options.mode);
+
+        let (options, _) = parse_options(args("--rank -").into_iter()).unwrap();
+        assert_eq!(Mode::Rank, options.mode);
+
+        let (options, _) = parse_options(args("--replay --speed 40 -").into_iter()).unwrap();
+        assert_eq!(Mode::Replay, options.mode);
+        assert_eq!(Duration::from_millis(40), options.delay);
+        assert!(parse_options(args("--speed fast").into_iter()).is_err());
+
+        let (options, _) = parse_options(args("--rig 2 -").into_iter()).unwrap();
+        assert_eq!(Mode::Rig(2), options.mode);
+        assert!(parse_options(args("--rig first").into_iter()).is_err());
+        assert!(parse_options(args("--win").into_iter()).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::board::Board;
+use crate::Game;
+use ansi_term::{Colour, Style};
+use std::io::{self, Write};
+use std::thread::sleep;
+use std::time::Duration;
+
+const BOARDS_PER_ROW: usize = 5;
+const CELL_WIDTH: usize = 3;
+
+/// Plays the draw in the terminal, redrawing every board after each number
+/// and waiting `delay` before the next one. Each frame is drawn on a cleared
+/// screen, so frames taller than the terminal don't leave stale lines behind.
+pub fn replay(boards: Vec<Board>, numbers: &[i32], delay: Duration) -> io::Result<()> {
+    let mut game = Game::new(boards);
+    let mut stdout = io::stdout();
+
+    for (draw_index, &num) in numbers.iter().enumerate() {
+        game.draw(draw_index, num);
+
+        let frame = render_frame(&game, numbers.len(), draw_index, num);
+        write!(
+            stdout,
+            "{}{}{}",
+            ansi_escapes::EraseScreen,
+            ansi_escapes::CursorTo::TopLeft,
+            frame
+        )?;
+        stdout.flush()?;
+
+        if game.is_over() {
+            break;
+        }
+        sleep(delay);
+    }
+    Ok(())
+}
+
+/// Every board after drawing `num`, with the newly marked cells reversed,
+/// completed lines in green and other marked cells in bold.
+pub fn render_frame(game: &Game, draws: usize, draw_index: usize, num: i32) -> String {
+    let boards = game.boards();
+    let won = (0..boards.len())
+        .filter(|&idx| game.won_at(idx).is_some())
+        .count();
+    let mut frame = format!(
+        "Draw {}/{}: {}, {} of {} boards won\n",
+        draw_index + 1,
+        draws,
+        num,
+        won,
+        boards.len()
+    );
+
+    for (band, playing) in boards.chunks(BOARDS_PER_ROW).enumerate() {
+        let first = band * BOARDS_PER_ROW;
+        let columns = playing
+            .iter()
+            .enumerate()
+            .map(|(idx, board)| render_board(first + idx, board, game.won_at(first + idx), num))
+            .collect::<Vec<_>>();
+        let height = columns.iter().map(Vec::len).max().unwrap_or_default();
+
+        frame += "\n";
+        for line in 0..height {
+            let cells = columns
+                .iter()
+                .zip(playing)
+                .map(|(column, board)| match column.get(line) {
+                    Some(text) => text.clone(),
+                    None => " ".repeat(board.cols() * CELL_WIDTH),
+                })
+                .collect::<Vec<_>>();
+            frame += &cells.join("   ");
+            frame += "\n";
+        }
+    }
+    frame
+}
+
+/// Title and rows of one board, each `cols * CELL_WIDTH` characters wide.
+fn render_board(idx: usize, board: &Board, won: Option<usize>, num: i32) -> Vec<String> {
+    let width = board.cols() * CELL_WIDTH;
+    let title = match won {
+        Some(draw_index) => format!("#{} won@{}", idx, draw_index + 1),
+        None => format!("#{}", idx),
+    };
+    let completed = board.completed_cells();
+    let newly_marked = board.position(num);
+
+    let mut lines = vec![format!("{:<width$.width$}", title, width = width)];
+    for (row, cells) in board.rows().into_iter().enumerate() {
+        let line = cells
+            .into_iter()
+            .enumerate()
+            .map(|(col, (value, marked))| {
+                let text = format!("{:>width$}", value, width = CELL_WIDTH);
+                let style = if !marked {
+                    Style::new()
+                } else if newly_marked == Some((row, col)) {
+                    Style::new().reverse()
+                } else if completed.contains(&(row, col)) {
+                    Colour::Green.bold()
+                } else {
+                    Style::new().bold()
+                };
+                style.paint(text).to_string()
+            })
+            .collect::<String>();
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GiantSquid;
+    use shared::{PuzzleInput, Solution};
+
+    #[test]
+    fn test_render_frame() {
+        let input = PuzzleInput::from_file("test-input.txt").unwrap();
+        let (boards, numbers) = GiantSquid::parse(&input).unwrap();
+        let mut game = Game::new(boards);
+        for (draw_index, &num) in numbers.iter().enumerate().take(12) {
+            game.draw(draw_index, num);
+        }
+
+        let frame = render_frame(&game, numbers.len(), 11, 24);
+
+        assert!(frame.starts_with("Draw 12/27: 24, 1 of 3 boards won\n\n#0"));
+        assert!(frame.contains("#2 won@12"));
+        let newly_marked = Style::new().reverse().paint(" 24").to_string();
+        assert_eq!(3, frame.matches(&newly_marked).count());
+        let completed = Colour::Green.bold().paint(" 14").to_string();
+        assert!(frame.contains(&completed));
+        assert_eq!(8, frame.lines().count());
+    }
+}
@@ -0,0 +1,95 @@
+pub mod line;
+pub mod options;
+
+use line::{Line, LineMode};
+use shared::{MyError, PuzzleInput, Solution};
+use std::collections::HashMap;
+
+pub struct HydrothermalVenture;
+
+impl Solution for HydrothermalVenture {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        input
+            .lines()
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| Line::from_input(&line))
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Answer1, MyError> {
+        Ok(count_dangerous_points(lines).get(LineMode::AxisAligned))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Answer2, MyError> {
+        Ok(count_dangerous_points(lines).get(LineMode::All))
+    }
+}
+
+/// Points covered by at least two lines, counted for each line mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Danger {
+    pub axis_aligned: usize,
+    pub all: usize,
+}
+
+impl Danger {
+    pub fn get(&self, mode: LineMode) -> usize {
+        match mode {
+            LineMode::AxisAligned => self.axis_aligned,
+            LineMode::All => self.all,
+        }
+    }
+}
+
+/// Draws every line once, keeping separate overlap counts for the
+/// axis-aligned lines and for all of them.
+pub fn count_dangerous_points(lines: &[Line]) -> Danger {
+    let mut field: HashMap<(i32, i32), (u32, u32)> = HashMap::new();
+    let mut danger = Danger {
+        axis_aligned: 0,
+        all: 0,
+    };
+
+    for line in lines {
+        let axis_aligned = LineMode::AxisAligned.includes(line);
+        for point in line.iter() {
+            let (axis_count, all_count) = field.entry(point).or_default();
+            if axis_aligned {
+                *axis_count += 1;
+                if *axis_count == 2 {
+                    danger.axis_aligned += 1;
+                }
+            }
+            *all_count += 1;
+            if *all_count == 2 {
+                danger.all += 1;
+            }
+        }
+    }
+    danger
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_dangerous_points() {
+        let input = PuzzleInput::from_file("test-input.txt").unwrap();
+        let lines = HydrothermalVenture::parse(&input).unwrap();
+
+        let expected = Danger {
+            axis_aligned: 5,
+            all: 12,
+        };
+        assert_eq!(expected, count_dangerous_points(&lines));
+        assert_eq!(5, HydrothermalVenture::part1(&lines).unwrap());
+        assert_eq!(12, HydrothermalVenture::part2(&lines).unwrap());
+    }
+}
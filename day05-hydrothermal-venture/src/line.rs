@@ -1,3 +1,8 @@
+use shared::{InputLine, MyError};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug)]
 pub struct Line {
     pub x1: i32,
     pub y1: i32,
@@ -10,71 +15,127 @@ impl Line {
         Line { x1, y1, x2, y2 }
     }
 
-    pub fn from_input(input: &str) -> Self {
-        let coords: Vec<i32> = input
-            .replace(" -> ", ",")
-            .split(",")
-            .map(|s| s.parse::<i32>().unwrap())
-            .collect();
-
-        Line::new(coords[0], coords[1], coords[2], coords[3])
+    pub fn from_input(input: &InputLine) -> Result<Self, MyError> {
+        let (start, end) = input.split_once(input.text.trim(), " -> ")?;
+        let (x1, y1) = input.split_once(start, ",")?;
+        let (x2, y2) = input.split_once(end, ",")?;
+
+        let line = Line::new(
+            input.parse(x1.trim(), "integer")?,
+            input.parse(y1.trim(), "integer")?,
+            input.parse(x2.trim(), "integer")?,
+            input.parse(y2.trim(), "integer")?,
+        );
+        if line.is_point() {
+            return Err(input.error(input.text.trim(), "line with two distinct ends"));
+        }
+        Ok(line)
     }
 
     pub fn is_parallel(&self) -> bool {
         self.x1 == self.x2 || self.y1 == self.y2
     }
 
+    /// Both ends at the same point.
+    pub fn is_point(&self) -> bool {
+        (self.x1, self.y1) == (self.x2, self.y2)
+    }
+
     pub fn iter(&self) -> LineIter {
         LineIter::new(self)
     }
 }
 
-pub struct LineIter<'a> {
-    line: &'a Line,
+/// Which lines are drawn on the field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineMode {
+    /// Horizontal and vertical lines only.
+    AxisAligned,
+    /// Lines of any slope.
+    All,
+}
+
+impl LineMode {
+    pub fn includes(self, line: &Line) -> bool {
+        match self {
+            LineMode::AxisAligned => line.is_parallel(),
+            LineMode::All => true,
+        }
+    }
+}
+
+impl FromStr for LineMode {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "axis" => Ok(LineMode::AxisAligned),
+            "all" => Ok(LineMode::All),
+            other => Err(MyError::InvalidArgument(format!(
+                "Unknown line mode '{}', expected axis or all",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for LineMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineMode::AxisAligned => write!(f, "axis"),
+            LineMode::All => write!(f, "all"),
+        }
+    }
+}
+
+/// Points of a line from its first end to its second, chosen with
+/// Bresenham's algorithm so that lines of any slope are covered without gaps.
+pub struct LineIter {
     curr: Option<(i32, i32)>,
-    direction: (i32, i32),
+    end: (i32, i32),
+    delta: (i64, i64),
+    step: (i32, i32),
+    error: i64,
 }
 
-impl<'a> LineIter<'a> {
-    pub fn new(line: &'a Line) -> Self {
-        let x_dir = if line.x1 == line.x2 {
-            0
-        } else {
-            (line.x2-line.x1) / (line.x2-line.x1).abs()
-        };
-        let y_dir = if line.y1 == line.y2 {
-            0
-        } else {
-            (line.y2-line.y1) / (line.y2-line.y1).abs()
-        };
-        let direction = (x_dir, y_dir);
+impl LineIter {
+    pub fn new(line: &Line) -> Self {
+        let x_diff = line.x2 as i64 - line.x1 as i64;
+        let y_diff = line.y2 as i64 - line.y1 as i64;
+        let (dx, dy) = (x_diff.abs(), -y_diff.abs());
 
         Self {
-            line,
-            curr: None,
-            direction,
+            curr: Some((line.x1, line.y1)),
+            end: (line.x2, line.y2),
+            delta: (dx, dy),
+            step: (x_diff.signum() as i32, y_diff.signum() as i32),
+            error: dx + dy,
         }
     }
 }
 
-impl<'a> Iterator for LineIter<'a> {
+impl Iterator for LineIter {
     type Item = (i32, i32);
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.curr {
-            Some(curr) => {
-                if curr == (self.line.x2, self.line.y2) {
-                    self.curr = None;
-                } else {
-                    let new_curr = (curr.0 + self.direction.0, curr.1 + self.direction.1);
-                    self.curr = Some(new_curr)
-                }
-            },
-            None => {
-                self.curr = Some((self.line.x1, self.line.y1))
-            }
+        let point = self.curr?;
+        if point == self.end {
+            self.curr = None;
+            return Some(point);
+        }
+
+        let (mut x, mut y) = point;
+        let doubled = 2 * self.error;
+        if doubled >= self.delta.1 {
+            self.error += self.delta.1;
+            x += self.step.0;
+        }
+        if doubled <= self.delta.0 {
+            self.error += self.delta.0;
+            y += self.step.1;
         }
-        self.curr
+        self.curr = Some((x, y));
+        Some(point)
     }
 }
 
@@ -85,23 +146,71 @@ mod tests {
     #[test]
     fn test_line_is_parallel() {
         let line = Line::new(1, 5, 1, 10);
-        assert_eq!(true, line.is_parallel());
+        assert!(line.is_parallel());
 
         let line = Line::new(10, 5, 1, 5);
-        assert_eq!(true, line.is_parallel());
+        assert!(line.is_parallel());
 
         let line = Line::new(10, 50, 1, 5);
-        assert_eq!(false, line.is_parallel());
+        assert!(!line.is_parallel());
     }
 
     #[test]
     fn test_line_from_input() {
-        let input = "0,9 -> 5,9";
-        let line = Line::from_input(input);
+        let input = InputLine::new("test", 1, "0,9 -> 5,9");
+        let line = Line::from_input(&input).unwrap();
 
         assert_eq!(0, line.x1);
         assert_eq!(9, line.y1);
         assert_eq!(5, line.x2);
         assert_eq!(9, line.y2);
     }
+
+    #[test]
+    fn test_line_from_invalid_input() {
+        let input = InputLine::new("test", 3, "0,9 -> 5;9");
+        let err = Line::from_input(&input).unwrap_err();
+
+        assert_eq!("test:3:8: expected ',', got '5;9'", err.to_string());
+
+        let input = InputLine::new("test", 4, "3,4 -> 3,4");
+        let err = Line::from_input(&input).unwrap_err();
+        assert_eq!(
+            "test:4:1: expected line with two distinct ends, got '3,4 -> 3,4'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_line_iter() {
+        let points = |line: Line| line.iter().collect::<Vec<_>>();
+
+        assert_eq!(vec![(3, 2), (2, 2), (1, 2)], points(Line::new(3, 2, 1, 2)));
+        assert_eq!(vec![(9, 7), (8, 8), (7, 9)], points(Line::new(9, 7, 7, 9)));
+        assert_eq!(
+            vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)],
+            points(Line::new(0, 0, 4, 2))
+        );
+        assert_eq!(
+            vec![(1, 5), (1, 4), (0, 3), (0, 2)],
+            points(Line::new(1, 5, 0, 2))
+        );
+        assert_eq!(vec![(2, 2)], points(Line::new(2, 2, 2, 2)));
+
+        let wide = Line::new(i32::MAX, 0, i32::MIN, 0);
+        let start = wide.iter().take(2).collect::<Vec<_>>();
+        assert_eq!(vec![(i32::MAX, 0), (i32::MAX - 1, 0)], start);
+    }
+
+    #[test]
+    fn test_line_mode() {
+        let diagonal = Line::new(0, 0, 4, 2);
+        assert!(!LineMode::AxisAligned.includes(&diagonal));
+        assert!(LineMode::All.includes(&diagonal));
+        assert!(LineMode::AxisAligned.includes(&Line::new(1, 5, 1, 10)));
+
+        assert_eq!(LineMode::AxisAligned, "axis".parse().unwrap());
+        assert_eq!("all", "all".parse::<LineMode>().unwrap().to_string());
+        assert!("diagonal".parse::<LineMode>().is_err());
+    }
 }
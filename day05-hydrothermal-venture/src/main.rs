@@ -1,37 +1,32 @@
-mod line;
-
-use line::Line;
-use shared::{file_lines, read_first_arg, MyError};
-use std::collections::{HashMap, hash_map::Entry};
+use day05_hydrothermal_venture::line::LineMode;
+use day05_hydrothermal_venture::options::parse_options;
+use day05_hydrothermal_venture::{count_dangerous_points, HydrothermalVenture};
+use serde_json::json;
+use shared::{parse_main_args, print_results, Format, MyError, PartResult, Solution};
 
 fn main() -> Result<(), MyError> {
-    let input = read_first_arg()?;
-
-    count_dangerous_points(&input)?;
-
-    Ok(())
-}
-
-fn count_dangerous_points(file_path: &str) -> Result<(), MyError> {
-    let mut field: HashMap<(i32, i32), i32> = HashMap::new();
-    let mut danger_counter = 0;
+    let (options, rest) = parse_options(std::env::args().skip(1))?;
+    let args = parse_main_args(rest.into_iter())?;
+    let lines = HydrothermalVenture::parse(&args.input.read()?)?;
 
-    for input_line in file_lines(file_path)? {
-        let line = Line::from_input(&input_line.unwrap());
-        for point in line.iter() {
-            match field.entry(point) {
-                Entry::Vacant(v) => {
-                    v.insert(1);
-                },
-                Entry::Occupied(o) => {
-                    if *o.get() == 1 {
-                        danger_counter += 1;
-                    }
-                    *(o.into_mut()) += 1;
-                }
-            }
+    // Both parts come from the same drawing of the lines
+    let danger = count_dangerous_points(&lines);
+    if let Some(mode) = options.lines {
+        let points = danger.get(mode);
+        match args.format {
+            Format::Text => println!("Dangerous points ({} lines): {}", mode, points),
+            Format::Json => println!("{}", json!({ "lines": mode.to_string(), "points": points })),
         }
+        return Ok(());
     }
-    println!("Danger counter (strait and diagonal lines): {}", danger_counter);
-    Ok(())
+
+    let results = vec![
+        PartResult::new(
+            HydrothermalVenture::DAY,
+            1,
+            Ok(danger.get(LineMode::AxisAligned)),
+        ),
+        PartResult::new(HydrothermalVenture::DAY, 2, Ok(danger.get(LineMode::All))),
+    ];
+    print_results(results, args.format)
 }
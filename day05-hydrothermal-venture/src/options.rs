@@ -0,0 +1,45 @@
+use crate::line::LineMode;
+use shared::{option_value, MyError};
+
+/// Options of the day's own binary on top of the common ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Print the dangerous points for these lines instead of the two answers.
+    pub lines: Option<LineMode>,
+}
+
+/// Takes `--lines axis|all` out of `args`, returning the other arguments
+/// untouched.
+pub fn parse_options<I: Iterator<Item = String>>(
+    mut args: I,
+) -> Result<(Options, Vec<String>), MyError> {
+    let mut options = Options::default();
+    let mut rest = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lines" => options.lines = Some(option_value(&mut args, "--lines")?.parse()?),
+            _ => rest.push(arg),
+        }
+    }
+    Ok((options, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_options() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        let (options, rest) = parse_options(args("- --format json").into_iter()).unwrap();
+        assert_eq!(Options::default(), options);
+        assert_eq!(vec!["-", "--format", "json"], rest);
+
+        let (options, _) = parse_options(args("--lines all -").into_iter()).unwrap();
+        assert_eq!(Some(LineMode::All), options.lines);
+        assert!(parse_options(args("--lines diagonal").into_iter()).is_err());
+        assert!(parse_options(args("--lines").into_iter()).is_err());
+    }
+}
@@ -0,0 +1,63 @@
+use shared::{MyError, PuzzleInput, Solution};
+
+pub struct TreacheryOfWhales;
+
+impl Solution for TreacheryOfWhales {
+    const DAY: u8 = 7;
+
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| input.unexpected_end("crab positions"))?;
+        line.parse_list::<i32>(line.text.trim(), ",", "integer")
+    }
+
+    fn part1(crabs: &Self::Input) -> Result<Self::Answer1, MyError> {
+        calculate_min_fuel_consuption(crabs.clone())
+    }
+
+    fn part2(crabs: &Self::Input) -> Result<Self::Answer2, MyError> {
+        calculate_min_fuel_consuption_avg(crabs.clone())
+    }
+}
+
+fn calculate_min_fuel_consuption(mut crabs: Vec<i32>) -> Result<i32, MyError> {
+    crabs.sort();
+    let median = crabs.get(crabs.len() / 2).unwrap();
+    let result = crabs
+        .iter()
+        .fold(0, |acc, crab| acc + (median - crab).abs());
+    Ok(result)
+}
+
+fn calculate_min_fuel_consuption_avg(crabs: Vec<i32>) -> Result<i32, MyError> {
+    let avg = crabs.iter().sum::<i32>() / crabs.len() as i32;
+    let result = (avg..avg + 2)
+        .map(|m| {
+            crabs.iter().fold(0, |acc, crab| {
+                acc + ((m - crab).abs() * (1 + (m - crab).abs())) / 2
+            })
+        })
+        .min()
+        .unwrap();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_min_fuel_consuption() {
+        let fuel = calculate_min_fuel_consuption(vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14]).unwrap();
+        assert_eq!(37, fuel);
+
+        let fuel = calculate_min_fuel_consuption_avg(vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14]).unwrap();
+        assert_eq!(168, fuel);
+    }
+}
@@ -0,0 +1,224 @@
+use shared::{MyError, PuzzleInput, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct SevenSegmentSearch;
+
+impl Solution for SevenSegmentSearch {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        parse_entries(input)
+    }
+
+    fn part1(entries: &Self::Input) -> Result<Self::Answer1, MyError> {
+        Ok(count_unque_digits(entries))
+    }
+
+    fn part2(entries: &Self::Input) -> Result<Self::Answer2, MyError> {
+        Ok(decode_signal(entries))
+    }
+}
+
+// Ten unique signal patterns and four output digits
+pub type Entry = (Vec<String>, Vec<String>);
+
+fn parse_entries(input: &PuzzleInput) -> Result<Vec<Entry>, MyError> {
+    input
+        .lines()
+        .filter(|line| !line.text.trim().is_empty())
+        .map(|line| {
+            let (left, right) = line.split_once(line.text.trim(), " | ")?;
+            let parse_codes = |codes: &str, count: usize| {
+                let codes = codes.split_whitespace().collect::<Vec<_>>();
+                if codes.len() != count {
+                    return Err(line.error(line.text, &format!("{} codes", count)));
+                }
+                match codes
+                    .iter()
+                    .find(|code| !code.chars().all(|ch| ('a'..='g').contains(&ch)))
+                {
+                    Some(code) => Err(line.error(code, "segments 'a' to 'g'")),
+                    None => Ok(codes.into_iter().map(String::from).collect()),
+                }
+            };
+            Ok((parse_codes(left, 10)?, parse_codes(right, 4)?))
+        })
+        .collect()
+}
+
+fn count_unque_digits(entries: &[Entry]) -> usize {
+    let unique_length: HashSet<usize> = vec![2, 3, 4, 7].into_iter().collect();
+
+    entries
+        .iter()
+        .map(|(_, output)| {
+            output
+                .iter()
+                .filter(|code| unique_length.contains(&code.len()))
+                .count()
+        })
+        .sum()
+}
+
+fn decode_signal(entries: &[Entry]) -> i32 {
+    let mut letters: HashMap<char, i32> = HashMap::with_capacity(7);
+
+    let mut segments: HashMap<i32, i32> = HashMap::with_capacity(7);
+    for i in 0..7 {
+        segments.insert(i, 1 << (6 - i));
+    }
+
+    let mut digits: HashMap<i32, i32> = HashMap::with_capacity(10);
+    digits.insert(0, get_number(&segments, vec![0, 1, 2, 4, 5, 6]));
+    digits.insert(1, get_number(&segments, vec![2, 5]));
+    digits.insert(2, get_number(&segments, vec![0, 2, 3, 4, 6]));
+    digits.insert(3, get_number(&segments, vec![0, 2, 3, 5, 6]));
+    digits.insert(4, get_number(&segments, vec![1, 2, 3, 5]));
+    digits.insert(5, get_number(&segments, vec![0, 1, 3, 5, 6]));
+    digits.insert(6, get_number(&segments, vec![0, 1, 3, 4, 5, 6]));
+    digits.insert(7, get_number(&segments, vec![0, 2, 5]));
+    digits.insert(8, get_number(&segments, vec![0, 1, 2, 3, 4, 5, 6]));
+    digits.insert(9, get_number(&segments, vec![0, 1, 2, 3, 5, 6]));
+
+    let pattern_to_digit: HashMap<i32, i32> = digits
+        .iter()
+        .map(|(digit, pattern)| (*pattern, *digit))
+        .collect();
+
+    let mut result = 0;
+
+    for (codes, output) in entries {
+        for ch in "abcdefg".chars() {
+            letters.insert(ch, 0b1111111);
+        }
+
+        let mut codes = codes.iter().map(String::as_str).collect::<Vec<_>>();
+        codes.sort_by_key(|code| code.len());
+
+        let mut seven_code = "";
+        for code in &codes {
+            let pattern = if code.len() == 2 {
+                digits[&1]
+            } else if code.len() == 3 {
+                seven_code = code;
+                digits[&7]
+            } else if code.len() == 4 {
+                digits[&4]
+            } else if code.len() == 5 {
+                if seven_code.chars().all(|ch| code.contains(ch)) {
+                    digits[&3]
+                } else {
+                    0
+                }
+            } else if code.len() == 6 {
+                if seven_code.chars().all(|ch| code.contains(ch)) {
+                    0
+                } else {
+                    digits[&6]
+                }
+            } else {
+                0
+            };
+
+            if pattern == 0 {
+                continue;
+            }
+
+            for ch in "abcdefg".chars() {
+                if code.contains(ch) {
+                    letters.insert(ch, letters[&ch] & pattern);
+                } else {
+                    letters.insert(ch, letters[&ch] & invert(pattern));
+                }
+            }
+        }
+
+        let mut order = 1000;
+        result += output
+            .iter()
+            .map(|code| code.chars().fold(0, |acc, ch| acc | letters[&ch]))
+            .map(|pattern| {
+                let num = pattern_to_digit[&pattern] * order;
+                order /= 10;
+                num
+            })
+            .sum::<i32>();
+    }
+
+    result
+}
+
+fn invert(num: i32) -> i32 {
+    let mut inverted = num;
+    for i in 0..7 {
+        inverted ^= 1 << i;
+    }
+    inverted
+}
+
+fn get_number(segments: &HashMap<i32, i32>, num_segments: Vec<i32>) -> i32 {
+    num_segments.iter().fold(0, |acc, seg| acc | segments[seg])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_unique_digits() {
+        let input = r#"
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+        "#;
+        let entries = parse_entries(&PuzzleInput::new("test", input.trim())).unwrap();
+
+        let unique_digits = count_unque_digits(&entries);
+
+        assert_eq!(26, unique_digits)
+    }
+
+    #[test]
+    fn test_decode_digits() {
+        let input = r#"
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+        "#;
+        let entries = parse_entries(&PuzzleInput::new("test", input.trim())).unwrap();
+
+        let unique_digits = decode_signal(&entries);
+
+        assert_eq!(61229, unique_digits)
+    }
+
+    #[test]
+    fn test_parse_invalid_entry() {
+        let input = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdx cefbgd gcbe";
+
+        let err = parse_entries(&PuzzleInput::new("test", input)).unwrap_err();
+
+        assert_eq!(
+            "test:1:70: expected segments 'a' to 'g', got 'cefdx'",
+            err.to_string()
+        );
+    }
+}
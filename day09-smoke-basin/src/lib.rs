@@ -0,0 +1,133 @@
+use shared::{Grid, MyError, Point, PuzzleInput, Solution};
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+
+pub struct SmokeBasin;
+
+impl Solution for SmokeBasin {
+    const DAY: u8 = 9;
+
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(heightmap: &Self::Input) -> Result<Self::Answer1, MyError> {
+        Ok(calculate_risk_level(heightmap))
+    }
+
+    fn part2(heightmap: &Self::Input) -> Result<Self::Answer2, MyError> {
+        Ok(find_largest_basins(heightmap))
+    }
+}
+
+fn calculate_risk_level(heightmap: &Grid<u8>) -> u32 {
+    get_low_points(heightmap)
+        .iter()
+        .map(|&p| heightmap[p] as u32 + 1)
+        .sum()
+}
+
+fn find_largest_basins(heightmap: &Grid<u8>) -> u32 {
+    let mut heap = BinaryHeap::new();
+
+    get_low_points(heightmap)
+        .iter()
+        .map(|&p| calculate_basin_size(heightmap, p))
+        .for_each(|size| heap.push(size));
+
+    (0..3).map(|_| heap.pop().unwrap_or(1)).product()
+}
+
+fn calculate_basin_size(heightmap: &Grid<u8>, start: Point) -> u32 {
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    let mut visited: HashSet<Point> = HashSet::new();
+
+    let mut size = 0;
+    while !queue.is_empty() {
+        let point = queue.pop_front().unwrap();
+        visited.insert(point);
+        size += 1;
+
+        heightmap
+            .neighbours4(point)
+            .filter(|&p| heightmap[p] != 9 && heightmap[p] > heightmap[point])
+            .for_each(|p| {
+                if !visited.contains(&p) {
+                    visited.insert(p);
+                    queue.push_back(p);
+                }
+            });
+    }
+    size
+}
+
+fn get_low_points(heightmap: &Grid<u8>) -> Vec<Point> {
+    heightmap
+        .iter()
+        .filter(|&(point, item)| {
+            heightmap
+                .neighbours4(point)
+                .all(|adj| item < &heightmap[adj])
+        })
+        .map(|(point, _)| point)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u8> {
+        let input = "
+            2199943210
+            3987894921
+            9856789892
+            8767896789
+            9899965678";
+        Grid::parse_digits(&PuzzleInput::new("test", input)).unwrap()
+    }
+
+    #[test]
+    fn test_calculate_risk_level() {
+        let input = example();
+
+        let expected = 15;
+        let actual = calculate_risk_level(&input);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_calculate_basin_size() {
+        let heightmap = example();
+
+        let expected = 3;
+        let actual = calculate_basin_size(&heightmap, (0, 1));
+        assert_eq!(expected, actual);
+
+        let expected = 9;
+        let actual = calculate_basin_size(&heightmap, (0, 9));
+        assert_eq!(expected, actual);
+
+        let expected = 14;
+        let actual = calculate_basin_size(&heightmap, (2, 2));
+        assert_eq!(expected, actual);
+
+        let expected = 9;
+        let actual = calculate_basin_size(&heightmap, (4, 6));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_find_largest_basins() {
+        let heightmap = example();
+
+        let expected = 1134;
+        let actual = find_largest_basins(&heightmap);
+        assert_eq!(expected, actual);
+    }
+}
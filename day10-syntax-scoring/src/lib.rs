@@ -0,0 +1,209 @@
+#[macro_use]
+extern crate lazy_static;
+
+use shared::{MyError, PuzzleInput, Solution};
+use std::collections::{HashMap, VecDeque};
+
+lazy_static! {
+    static ref CLOSE_TO_OPEN: HashMap<char, char> =
+        HashMap::<_, _>::from_iter([(')', '('), (']', '['), ('}', '{'), ('>', '<'),]);
+    static ref OPEN_TO_CLOSE: HashMap<char, char> =
+        HashMap::<_, _>::from_iter([('(', ')'), ('[', ']'), ('{', '}'), ('<', '>'),]);
+    static ref CORRUPT_SCORES: HashMap<char, u32> =
+        HashMap::<_, _>::from_iter([(')', 3), (']', 57), ('}', 1197), ('>', 25137),]);
+    static ref INCOMPLETE_SCORES: HashMap<char, u64> =
+        HashMap::<_, _>::from_iter([(')', 1), (']', 2), ('}', 3), ('>', 4),]);
+}
+
+pub struct SyntaxScoring;
+
+impl Solution for SyntaxScoring {
+    const DAY: u8 = 10;
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        parse_lines(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Self::Answer1, MyError> {
+        Ok(calculate_total_syntax_error_score(lines))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Self::Answer2, MyError> {
+        calculate_incomplete_middle_score(lines)
+    }
+}
+
+fn parse_lines(input: &PuzzleInput) -> Result<Vec<String>, MyError> {
+    input
+        .lines()
+        .map(|line| {
+            let text = line.text.trim();
+            match text
+                .char_indices()
+                .find(|(_, ch)| !CLOSE_TO_OPEN.contains_key(ch) && !OPEN_TO_CLOSE.contains_key(ch))
+            {
+                Some((idx, ch)) => Err(line.error(&text[idx..idx + ch.len_utf8()], "bracket")),
+                None => Ok(text.to_string()),
+            }
+        })
+        .collect()
+}
+
+fn calculate_total_syntax_error_score(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .filter_map(|line| get_first_illegal_char(line))
+        .map(|ch| CORRUPT_SCORES[&ch])
+        .sum()
+}
+
+fn get_first_illegal_char(line: &str) -> Option<char> {
+    let mut stack = VecDeque::new();
+    for ch in line.chars() {
+        if CLOSE_TO_OPEN.contains_key(&ch) {
+            match stack.pop_front() {
+                Some(prev) if prev != CLOSE_TO_OPEN[&ch] => return Some(ch),
+                None => return Some(ch),
+                _ => (),
+            }
+        } else {
+            stack.push_front(ch);
+        }
+    }
+    None
+}
+
+fn calculate_incomplete_middle_score(lines: &[String]) -> Result<u64, MyError> {
+    let mut scores = lines
+        .iter()
+        .filter_map(|line| get_completion_string(line))
+        .map(|s| {
+            s.chars()
+                .fold(0, |acc, ch| acc * 5 + INCOMPLETE_SCORES[&ch])
+        })
+        .collect::<Vec<_>>();
+
+    if scores.is_empty() {
+        return Err(MyError::NoAnswer("no incomplete lines".to_string()));
+    }
+    scores.sort_unstable();
+    Ok(scores[scores.len() / 2])
+}
+
+fn get_completion_string(line: &str) -> Option<String> {
+    let mut stack = VecDeque::new();
+    for ch in line.chars() {
+        if CLOSE_TO_OPEN.contains_key(&ch) {
+            match stack.pop_front() {
+                Some(prev) if prev != CLOSE_TO_OPEN[&ch] => return None,
+                None => return None,
+                _ => (),
+            }
+        } else {
+            stack.push_front(ch);
+        }
+    }
+
+    if stack.is_empty() {
+        return None;
+    }
+
+    Some(stack.iter().map(|ch| OPEN_TO_CLOSE[ch]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_total_syntax_error_score() {
+        let input = r#"
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+        "#;
+        let lines = parse_lines(&PuzzleInput::new("test", input.trim())).unwrap();
+
+        let expected = 26397;
+        let actual = calculate_total_syntax_error_score(&lines);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_get_first_illegal_char() {
+        let expected = None;
+        let actual = get_first_illegal_char("[({(<(())[]>[[{[]{<()<>>");
+        assert_eq!(expected, actual);
+
+        let expected = Some('}');
+        let actual = get_first_illegal_char("{([(<{}[<>[]}>{[]{[(<()>");
+        assert_eq!(expected, actual);
+
+        let expected = Some(')');
+        let actual = get_first_illegal_char("[[<[([]))<([[{}[[()]]]");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_get_completion_string() {
+        let expected = Some(String::from("}}]])})]"));
+        let actual = get_completion_string("[({(<(())[]>[[{[]{<()<>>");
+        assert_eq!(expected, actual);
+
+        let expected = Some(String::from(")}>]})"));
+        let actual = get_completion_string("[(()[<>])]({[<{<<[]>>(");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_calculate_incomplete_middle_score() {
+        let input = r#"
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+        "#;
+        let lines = parse_lines(&PuzzleInput::new("test", input.trim())).unwrap();
+
+        let expected = 288957;
+        let actual = calculate_incomplete_middle_score(&lines).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_no_incomplete_lines() {
+        let lines = parse_lines(&PuzzleInput::new("test", "()\n(]")).unwrap();
+        assert_eq!(
+            "No answer: no incomplete lines",
+            calculate_incomplete_middle_score(&lines)
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(calculate_incomplete_middle_score(&[]).is_err());
+    }
+
+    #[test]
+    fn test_parse_lines_rejects_unknown_chars() {
+        let input = PuzzleInput::new("test", "[({(<(())[]>\n[(()[<>]).]");
+        let err = parse_lines(&input).unwrap_err();
+
+        assert_eq!("test:2:10: expected bracket, got '.'", err.to_string());
+    }
+}
@@ -0,0 +1,195 @@
+extern crate ansi_escapes;
+
+pub mod options;
+
+use ansi_term::Style;
+use shared::{MyError, Point, PuzzleInput, Solution};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::thread::sleep;
+use std::time::Duration;
+
+pub struct DumboOctopus;
+
+impl Solution for DumboOctopus {
+    const DAY: u8 = 11;
+
+    type Input = shared::Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        shared::Grid::parse_digits(input)
+    }
+
+    fn part1(initial_state: &Self::Input) -> Result<Self::Answer1, MyError> {
+        let grid = Grid::new(initial_state.clone(), false);
+        Ok(count_flashes(grid, 100))
+    }
+
+    fn part2(initial_state: &Self::Input) -> Result<Self::Answer2, MyError> {
+        let grid = Grid::new(initial_state.clone(), false);
+        Ok(get_step_when_all_flashed(grid))
+    }
+}
+
+/// Plays out part 2 on the terminal, one frame per flash, and returns the
+/// step on which every octopus flashes.
+pub fn animate(initial_state: &shared::Grid<u8>) -> usize {
+    get_step_when_all_flashed(Grid::new(initial_state.clone(), true))
+}
+
+fn count_flashes(mut grid: Grid, steps: usize) -> usize {
+    for _ in 0..steps {
+        grid.tick();
+    }
+    grid.flash_counter
+}
+
+fn get_step_when_all_flashed(mut grid: Grid) -> usize {
+    while !grid.all_flashed() {
+        grid.tick();
+    }
+    grid.step
+}
+
+struct Grid {
+    grid: shared::Grid<u8>,
+    pub step: usize,
+    pub flash_counter: usize,
+    flash_counter_on_step: usize,
+    print_steps: bool,
+}
+
+impl Grid {
+    pub fn new(initial_state: shared::Grid<u8>, print_steps: bool) -> Self {
+        Grid {
+            grid: initial_state,
+            step: 0,
+            flash_counter: 0,
+            flash_counter_on_step: 0,
+            print_steps,
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.step += 1;
+        self.flash_counter_on_step = 0;
+
+        let mut ready_to_flash = self.increase_energy_levels();
+        self.print();
+
+        while !ready_to_flash.is_empty() {
+            let octopus = ready_to_flash.pop_front().unwrap();
+            for oct in self.flash(octopus) {
+                ready_to_flash.push_back(oct)
+            }
+            self.print();
+        }
+
+        self.reset_flashed();
+        self.print();
+    }
+
+    pub fn all_flashed(&self) -> bool {
+        self.flash_counter_on_step == self.grid.len()
+    }
+
+    fn increase_energy_levels(&mut self) -> VecDeque<Point> {
+        let mut ready_to_flash = VecDeque::new();
+
+        for point in self.grid.points() {
+            self.grid[point] += 1;
+            if self.grid[point] == 10 {
+                ready_to_flash.push_back(point);
+                self.flash_counter_on_step += 1;
+            }
+        }
+
+        ready_to_flash
+    }
+
+    fn flash(&mut self, cur: Point) -> Vec<Point> {
+        let mut ready_to_flash = vec![];
+        for point in self.grid.neighbours8(cur) {
+            self.grid[point] += 1;
+            if self.grid[point] == 10 {
+                self.flash_counter_on_step += 1;
+                ready_to_flash.push(point);
+            }
+        }
+        ready_to_flash
+    }
+
+    fn reset_flashed(&mut self) {
+        for energy in self.grid.values_mut().filter(|energy| **energy > 9) {
+            *energy = 0;
+        }
+        self.flash_counter += self.flash_counter_on_step;
+    }
+
+    fn print(&self) {
+        if self.print_steps {
+            print!("{}", self);
+            sleep(Duration::from_millis(20));
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = format!("{}", ansi_escapes::EraseLines(self.grid.rows() as u16 + 3));
+        s += format!(
+            "Step: {}, Flashes: {}\n\n",
+            self.step,
+            self.flash_counter + self.flash_counter_on_step
+        )
+        .as_str();
+
+        for row in 0..self.grid.rows() {
+            s += self
+                .grid
+                .row(row)
+                .iter()
+                .map(|&a| {
+                    if a > 9 {
+                        format!("{} ", Style::new().bold().paint("0"))
+                    } else {
+                        format!("{} ", a)
+                    }
+                })
+                .collect::<String>()
+                .as_str();
+            s += "\n";
+        }
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_flashes() {
+        let initial_state = shared::Grid::from_rows(vec![
+            vec![5, 4, 8, 3, 1, 4, 3, 2, 2, 3],
+            vec![2, 7, 4, 5, 8, 5, 4, 7, 1, 1],
+            vec![5, 2, 6, 4, 5, 5, 6, 1, 7, 3],
+            vec![6, 1, 4, 1, 3, 3, 6, 1, 4, 6],
+            vec![6, 3, 5, 7, 3, 8, 5, 4, 7, 8],
+            vec![4, 1, 6, 7, 5, 2, 4, 6, 4, 5],
+            vec![2, 1, 7, 6, 8, 4, 1, 7, 2, 1],
+            vec![6, 8, 8, 2, 8, 8, 1, 1, 3, 4],
+            vec![4, 8, 4, 6, 8, 4, 8, 5, 5, 4],
+            vec![5, 2, 8, 3, 7, 5, 1, 5, 2, 6],
+        ])
+        .unwrap();
+        let mut grid = Grid::new(initial_state, false);
+        for _ in 0..100 {
+            grid.tick();
+        }
+        let expected = 1656;
+        assert_eq!(expected, grid.flash_counter);
+    }
+}
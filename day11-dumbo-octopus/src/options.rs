@@ -0,0 +1,42 @@
+use shared::MyError;
+
+/// Options of the day's own binary on top of the common ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Show the octopuses flashing while part 2 is solved.
+    pub animate: bool,
+}
+
+/// Takes `--animate` out of `args`, returning the other arguments untouched.
+pub fn parse_options<I: Iterator<Item = String>>(
+    args: I,
+) -> Result<(Options, Vec<String>), MyError> {
+    let mut options = Options::default();
+    let mut rest = vec![];
+
+    for arg in args {
+        match arg.as_str() {
+            "--animate" => options.animate = true,
+            _ => rest.push(arg),
+        }
+    }
+    Ok((options, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_options() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        let (options, rest) = parse_options(args("- --format json").into_iter()).unwrap();
+        assert_eq!(Options::default(), options);
+        assert_eq!(vec!["-", "--format", "json"], rest);
+
+        let (options, rest) = parse_options(args("--animate input.txt").into_iter()).unwrap();
+        assert!(options.animate);
+        assert_eq!(vec!["input.txt"], rest);
+    }
+}
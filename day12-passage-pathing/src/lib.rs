@@ -0,0 +1,246 @@
+use shared::graph::Graph;
+use shared::{input_lines, MyError, PuzzleInput, Solution};
+use std::collections::{HashMap, VecDeque};
+
+pub struct PassagePathing;
+
+impl Solution for PassagePathing {
+    const DAY: u8 = 12;
+
+    type Input = Caves;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        string_to_edges(&input.path, &input.text)
+    }
+
+    fn part1(caves: &Self::Input) -> Result<Self::Answer1, MyError> {
+        Ok(count_all_paths(caves))
+    }
+
+    fn part2(caves: &Self::Input) -> Result<Self::Answer2, MyError> {
+        Ok(count_all_paths_visit_small_twice(caves))
+    }
+}
+
+/// Cave system with every cave numbered in the order it first appears.
+#[derive(Debug, Default)]
+pub struct Caves {
+    names: Vec<String>,
+    adjacent: Vec<Vec<usize>>,
+}
+
+impl Caves {
+    fn find(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    fn name(&self, cave: usize) -> &str {
+        &self.names[cave]
+    }
+
+    fn add(&mut self, name: &str) -> usize {
+        self.find(name).unwrap_or_else(|| {
+            self.names.push(name.to_string());
+            self.adjacent.push(vec![]);
+            self.names.len() - 1
+        })
+    }
+
+    fn is_small(&self, cave: usize) -> bool {
+        let name = self.name(cave);
+        name == name.to_lowercase()
+    }
+}
+
+impl Graph for Caves {
+    type Node = usize;
+
+    fn neighbours(&self, &cave: &usize) -> Vec<(usize, u64)> {
+        self.adjacent[cave].iter().map(|&adj| (adj, 1)).collect()
+    }
+}
+
+fn count_all_paths(caves: &Caves) -> usize {
+    let start = match caves.find("start") {
+        Some(start) => start,
+        None => return 0,
+    };
+    let mut visited = VecDeque::<usize>::new();
+    visited.push_back(start);
+    let can_be_visited =
+        |visited: &VecDeque<usize>, adj: usize| !caves.is_small(adj) || !visited.contains(&adj);
+    dfs(caves, &mut visited, start, &can_be_visited).len()
+}
+
+fn count_all_paths_visit_small_twice(caves: &Caves) -> usize {
+    let start = match caves.find("start") {
+        Some(start) => start,
+        None => return 0,
+    };
+    let mut visited = VecDeque::<usize>::new();
+    visited.push_back(start);
+    let can_be_visited = |visited: &VecDeque<usize>, adj: usize| {
+        if adj == start {
+            return false;
+        }
+        if caves.is_small(adj) {
+            let map = visited.iter().filter(|&&c| caves.is_small(c)).fold(
+                HashMap::new(),
+                |mut map, c| {
+                    *map.entry(c).or_insert(0) += 1;
+                    map
+                },
+            );
+            return !map.contains_key(&adj) || map.iter().all(|(_, v)| *v < 2);
+        }
+        true
+    };
+    dfs(caves, &mut visited, start, &can_be_visited).len()
+}
+
+fn dfs<F>(
+    caves: &Caves,
+    visited: &mut VecDeque<usize>,
+    start: usize,
+    can_be_visited: &F,
+) -> Vec<Vec<usize>>
+where
+    F: Fn(&VecDeque<usize>, usize) -> bool,
+{
+    if caves.name(start) == "end" {
+        return vec![Vec::from_iter(visited.iter().copied())];
+    }
+
+    let next_caves = caves
+        .neighbours(&start)
+        .into_iter()
+        .map(|(adj, _)| adj)
+        .filter(|&adj| can_be_visited(visited, adj))
+        .collect::<Vec<usize>>();
+
+    let mut paths = vec![];
+    for cave in next_caves {
+        visited.push_back(cave);
+        paths.append(&mut dfs(caves, visited, cave, can_be_visited));
+        visited.pop_back();
+    }
+    paths
+}
+
+fn string_to_edges(path: &str, s: &str) -> Result<Caves, MyError> {
+    let mut caves = Caves::default();
+    for line in input_lines(path, s).filter(|l| !l.text.trim().is_empty()) {
+        let (from, to) = line.split_once(line.text.trim(), "-")?;
+        let from = caves.add(from.trim());
+        let to = caves.add(to.trim());
+        caves.adjacent[from].push(to);
+        caves.adjacent[to].push(from);
+    }
+    Ok(caves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_all_paths() {
+        let input = r#"
+        start-A
+        start-b
+        A-c
+        A-b
+        b-d
+        A-end
+        b-end"#
+            .to_string();
+        let caves = string_to_edges("test", &input).unwrap();
+
+        let expected = 10;
+        let actual = count_all_paths(&caves);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_conut_all_paths_2() {
+        let input = r#"
+        dc-end
+        HN-start
+        start-kj
+        dc-start
+        dc-HN
+        LN-dc
+        HN-end
+        kj-sa
+        kj-HN
+        kj-dc"#
+            .to_string();
+        let caves = string_to_edges("test", &input).unwrap();
+
+        println!("{:?}", caves);
+
+        let expected = 19;
+        let actual = count_all_paths(&caves);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_count_all_paths_visit_small_twice() {
+        let input = r#"
+        start-A
+        start-b
+        A-c
+        A-b
+        b-d
+        A-end
+        b-end"#
+            .to_string();
+        let caves = string_to_edges("test", &input).unwrap();
+
+        let expected = 36;
+        let actual = count_all_paths_visit_small_twice(&caves);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_count_all_paths_visit_small_twice_2() {
+        let input = r#"
+        dc-end
+        HN-start
+        start-kj
+        dc-start
+        dc-HN
+        LN-dc
+        HN-end
+        kj-sa
+        kj-HN
+        kj-dc"#
+            .to_string();
+        let caves = string_to_edges("test", &input).unwrap();
+
+        let expected = 103;
+        let actual = count_all_paths_visit_small_twice(&caves);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_shortest_route() {
+        let input = r#"
+        start-A
+        start-b
+        A-c
+        A-b
+        b-d
+        A-end
+        b-end"#;
+        let caves = string_to_edges("test", input).unwrap();
+
+        let start = caves.find("start").unwrap();
+        let route = shared::graph::bfs(&caves, start, |&c| caves.name(c) == "end").unwrap();
+
+        assert_eq!(2, route.cost);
+        assert_eq!("start", caves.name(route.nodes[0]));
+    }
+}
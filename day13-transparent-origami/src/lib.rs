@@ -0,0 +1,209 @@
+use shared::{input_lines, unexpected_end, MyError, PuzzleInput, Solution};
+use std::{collections::HashSet, fmt::Display};
+
+pub struct TransparentOrigami;
+
+impl Solution for TransparentOrigami {
+    const DAY: u8 = 13;
+
+    type Input = (HashSet<Point>, Vec<FoldCommand>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        read_input(&input.path, &input.text)
+    }
+
+    fn part1((grid, commands): &Self::Input) -> Result<Self::Answer1, MyError> {
+        let command = commands
+            .first()
+            .ok_or_else(|| MyError::NoAnswer("no fold instructions".to_string()))?;
+        let mut paper = Paper::new(grid.clone());
+        paper.fold(command);
+
+        Ok(paper.points_count())
+    }
+
+    fn part2((grid, commands): &Self::Input) -> Result<Self::Answer2, MyError> {
+        let mut paper = Paper::new(grid.clone());
+        for command in commands {
+            paper.fold(command);
+        }
+        if paper.points_count() == 0 {
+            return Err(MyError::NoAnswer("no dots on the paper".to_string()));
+        }
+
+        Ok(paper.to_string().trim_end().to_string())
+    }
+}
+
+pub type FoldCommand = (FoldAxis, usize);
+
+fn read_input(file_path: &str, input: &str) -> Result<(HashSet<Point>, Vec<FoldCommand>), MyError> {
+    let mut grid: HashSet<Point> = HashSet::new();
+    let mut commands: Vec<FoldCommand> = vec![];
+
+    for l in input_lines(file_path, input) {
+        let text = l.text.trim();
+        if let Some(fold) = text.strip_prefix("fold along ") {
+            match l.split_once(fold, "=")? {
+                ("x", pos) => commands.push((FoldAxis::X, l.parse(pos, "integer")?)),
+                ("y", pos) => commands.push((FoldAxis::Y, l.parse(pos, "integer")?)),
+                (axis, _) => return Err(l.error(axis, "fold axis 'x' or 'y'")),
+            }
+        } else if !text.is_empty() {
+            let (x, y) = l.split_once(text, ",")?;
+            grid.insert(Point(l.parse(x, "integer")?, l.parse(y, "integer")?));
+        }
+    }
+    if commands.is_empty() {
+        return Err(unexpected_end(file_path, input, "fold instruction"));
+    }
+
+    Ok((grid, commands))
+}
+
+#[derive(Debug)]
+pub enum FoldAxis {
+    X,
+    Y,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Point(usize, usize);
+
+pub struct Paper {
+    grid: HashSet<Point>,
+}
+
+impl Paper {
+    pub fn new(grid: HashSet<Point>) -> Self {
+        Paper { grid }
+    }
+
+    pub fn points_count(&self) -> usize {
+        self.grid.len()
+    }
+
+    pub fn fold(&mut self, command: &FoldCommand) {
+        match *command {
+            (FoldAxis::Y, pos) => self.fold_y(pos),
+            (FoldAxis::X, pos) => self.fold_x(pos),
+        }
+    }
+
+    pub fn fold_y(&mut self, y: usize) {
+        let points_to_fold = self
+            .grid
+            .iter()
+            .filter_map(|p| if p.1 > y { Some(Point(p.0, p.1)) } else { None })
+            .collect::<Vec<_>>();
+
+        for point in points_to_fold {
+            let folded = Point(point.0, 2 * y - point.1);
+            if !self.grid.contains(&folded) {
+                self.grid.insert(folded);
+            }
+            self.grid.remove(&point);
+        }
+    }
+
+    pub fn fold_x(&mut self, pos: usize) {
+        let points_to_fold = self
+            .grid
+            .iter()
+            .filter_map(|p| {
+                if p.0 > pos {
+                    Some(Point(p.0, p.1))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for point in points_to_fold {
+            let folded = Point(2 * pos - point.0, point.1);
+            if !self.grid.contains(&folded) {
+                self.grid.insert(folded);
+            }
+            self.grid.remove(&point);
+        }
+    }
+}
+
+impl Display for Paper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cols = self.grid.iter().map(|p| p.0 + 1).max().unwrap_or(0);
+        let rows = self.grid.iter().map(|p| p.1 + 1).max().unwrap_or(0);
+        let mut s = String::new();
+        for i in 0..rows {
+            for j in 0..cols {
+                s += if self.grid.contains(&Point(j, i)) {
+                    "#"
+                } else {
+                    "."
+                };
+            }
+            s += "\n";
+        }
+
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_fold() {
+        let grid: HashSet<Point> = vec![
+            Point(6, 10),
+            Point(0, 14),
+            Point(9, 10),
+            Point(0, 3),
+            Point(10, 4),
+            Point(4, 11),
+            Point(6, 0),
+            Point(6, 12),
+            Point(4, 1),
+            Point(0, 13),
+            Point(10, 12),
+            Point(3, 4),
+            Point(3, 0),
+            Point(8, 4),
+            Point(1, 10),
+            Point(2, 14),
+            Point(8, 10),
+            Point(9, 0),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut paper = Paper::new(grid);
+        paper.fold_y(7);
+
+        assert_eq!(17, paper.points_count());
+    }
+
+    #[test]
+    fn test_no_dots_or_folds() {
+        let input = PuzzleInput::new("test", "fold along x=1");
+        let parsed = TransparentOrigami::parse(&input).unwrap();
+
+        assert_eq!(0, TransparentOrigami::part1(&parsed).unwrap());
+        assert_eq!(
+            "No answer: no dots on the paper",
+            TransparentOrigami::part2(&parsed).unwrap_err().to_string()
+        );
+        assert_eq!("", Paper::new(HashSet::new()).to_string());
+
+        let no_folds = (parsed.0, vec![]);
+        assert_eq!(
+            "No answer: no fold instructions",
+            TransparentOrigami::part1(&no_folds)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
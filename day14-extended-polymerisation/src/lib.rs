@@ -0,0 +1,180 @@
+use shared::{input_lines, unexpected_end, MyError, PuzzleInput, Solution};
+use std::collections::HashMap;
+
+pub struct ExtendedPolymerisation;
+
+impl Solution for ExtendedPolymerisation {
+    const DAY: u8 = 14;
+
+    type Input = (String, HashMap<String, char>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        read_input(&input.path, &input.text)
+    }
+
+    fn part1((template, rules): &Self::Input) -> Result<Self::Answer1, MyError> {
+        Ok(most_minus_least_after(template, rules, 10))
+    }
+
+    fn part2((template, rules): &Self::Input) -> Result<Self::Answer2, MyError> {
+        Ok(most_minus_least_after(template, rules, 40))
+    }
+}
+
+fn most_minus_least_after(template: &str, rules: &HashMap<String, char>, steps: usize) -> u64 {
+    let mut poly = Polymerisation::new(template.to_string(), rules.clone());
+    for _ in 0..steps {
+        poly.step();
+    }
+    let (most, least) = poly.most_least_count();
+
+    most - least
+}
+
+struct Polymerisation {
+    rules: HashMap<String, char>,
+    pairs: HashMap<String, u64>,
+    elements: HashMap<char, u64>,
+}
+
+impl Polymerisation {
+    pub fn new(template: String, rules: HashMap<String, char>) -> Self {
+        let mut pairs: HashMap<String, u64> = HashMap::new();
+        let mut elements: HashMap<char, u64> = HashMap::new();
+
+        let mut prev_char: Option<char> = None;
+        for ch in template.chars() {
+            *elements.entry(ch).or_insert(0) += 1;
+            if let Some(prev_ch) = prev_char {
+                let pair = format!("{}{}", prev_ch, ch);
+                *pairs.entry(pair).or_insert(0) += 1;
+            }
+            prev_char = Some(ch);
+        }
+
+        Polymerisation {
+            rules,
+            pairs,
+            elements,
+        }
+    }
+
+    /// Pairs without a rule are left as they are.
+    pub fn step(&mut self) {
+        let mut new_pairs: HashMap<String, u64> = HashMap::new();
+        for (pair, &count) in &self.pairs {
+            let new_element = match self.rules.get(pair) {
+                Some(&element) => element,
+                None => {
+                    *new_pairs.entry(pair.clone()).or_insert(0) += count;
+                    continue;
+                }
+            };
+            *self.elements.entry(new_element).or_insert(0) += count;
+
+            let mut elements = pair.chars();
+            let (first, second) = (elements.next().unwrap(), elements.next().unwrap());
+
+            let new_pair1 = format!("{}{}", first, new_element);
+            *new_pairs.entry(new_pair1).or_insert(0) += count;
+
+            let new_pair2 = format!("{}{}", new_element, second);
+            *new_pairs.entry(new_pair2).or_insert(0) += count;
+        }
+        self.pairs = new_pairs;
+    }
+
+    pub fn most_least_count(&self) -> (u64, u64) {
+        let mut most = u64::MIN;
+        let mut least = u64::MAX;
+
+        for &count in self.elements.values() {
+            most = count.max(most);
+            least = count.min(least);
+        }
+
+        (most, least)
+    }
+}
+
+fn read_input(path: &str, input: &str) -> Result<(String, HashMap<String, char>), MyError> {
+    let mut lines = input_lines(path, input);
+    let template = lines
+        .next()
+        .ok_or_else(|| unexpected_end(path, input, "polymer template"))?;
+    if template.text.trim().is_empty() {
+        return Err(template.error(template.text, "polymer template"));
+    }
+
+    let mut rules = HashMap::new();
+    for l in lines.filter(|l| !l.text.trim().is_empty()) {
+        let (pair, element) = l.split_once(l.text.trim(), " -> ")?;
+        if pair.chars().count() != 2 {
+            return Err(l.error(pair, "pair of elements"));
+        }
+        let mut element_chars = element.chars();
+        match (element_chars.next(), element_chars.next()) {
+            (Some(ch), None) => rules.insert(pair.to_string(), ch),
+            _ => return Err(l.error(element, "single element")),
+        };
+    }
+
+    Ok((template.text.trim().to_string(), rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_most_and_least_common_elements() {
+        let input = r#"NNCB
+
+        CH -> B
+        HH -> N
+        CB -> H
+        NH -> C
+        HB -> C
+        HC -> B
+        HN -> C
+        NN -> C
+        BH -> H
+        NC -> B
+        NB -> B
+        BN -> B
+        BB -> N
+        BC -> B
+        CC -> N
+        CN -> C
+        "#;
+        let (template, rules) = read_input("test", input).unwrap();
+
+        let mut poly = Polymerisation::new(template.to_string(), rules);
+        for _ in 0..10 {
+            poly.step();
+        }
+
+        let (most, least) = poly.most_least_count();
+
+        assert_eq!(1588, most - least);
+    }
+
+    #[test]
+    fn test_pairs_without_rules() {
+        let (template, rules) = read_input("test", "NNB\n\nNN -> C\n").unwrap();
+
+        // NNB becomes NCNB, then NCNB again as only NN has a rule
+        assert_eq!(1, most_minus_least_after(&template, &rules, 1));
+        assert_eq!(1, most_minus_least_after(&template, &rules, 10));
+    }
+
+    #[test]
+    fn test_empty_template() {
+        assert_eq!(
+            "test:1:1: expected polymer template, got ''",
+            read_input("test", "\nNN -> C\n").unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use ansi_term::Colour::Green;
+use shared::graph::{self, Graph, Path};
+use shared::{Grid, MyError, Point, PuzzleInput, Solution};
+
+pub struct Chiton;
+
+impl Solution for Chiton {
+    const DAY: u8 = 15;
+
+    type Input = Grid<u8>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    /// Risk levels must be 1-9 for the full map to wrap around correctly and
+    /// for the search heuristic to hold.
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError> {
+        for line in input.lines() {
+            let text = line.text.trim();
+            if let Some(idx) = text.find('0') {
+                return Err(line.error(&text[idx..idx + 1], "risk level 1-9"));
+            }
+        }
+        Grid::parse_digits(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Self::Answer1, MyError> {
+        lowest_risk(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Self::Answer2, MyError> {
+        lowest_risk(&get_full_grid(grid))
+    }
+}
+
+fn lowest_risk(grid: &Grid<u8>) -> Result<u64, MyError> {
+    find_shortest_path(grid)
+        .map(|path| path.cost)
+        .ok_or_else(|| MyError::NoAnswer("no path to the bottom-right corner".to_string()))
+}
+
+pub fn visualise_path(grid: &Grid<u8>, path: &Path<Point>) {
+    for (point, node) in grid.iter() {
+        if path.nodes.contains(&point) {
+            print!("{}", Green.paint(node.to_string()))
+        } else {
+            print!("{}", node);
+        }
+        if point.1 == grid.cols() - 1 {
+            println!();
+        }
+    }
+}
+
+/// Moving onto a position costs its risk level.
+struct RiskMap<'a>(&'a Grid<u8>);
+
+impl Graph for RiskMap<'_> {
+    type Node = Point;
+
+    fn neighbours(&self, &point: &Point) -> Vec<(Point, u64)> {
+        self.0
+            .neighbours4(point)
+            .map(|next| (next, self.0[next] as u64))
+            .collect()
+    }
+}
+
+pub fn get_full_grid(grid: &Grid<u8>) -> Grid<u8> {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    Grid::from_fn(rows * 5, cols * 5, |(row, col)| {
+        let risk = grid[(row % rows, col % cols)] as usize + row / rows + col / cols;
+        ((risk - 1) % 9 + 1) as u8
+    })
+}
+
+pub fn find_shortest_path(grid: &Grid<u8>) -> Option<Path<Point>> {
+    if grid.is_empty() {
+        return None;
+    }
+    let goal = (grid.rows() - 1, grid.cols() - 1);
+    // Risk levels are at least 1, so the Manhattan distance never overestimates
+    let distance = |&(row, col): &Point| (goal.0 - row + goal.1 - col) as u64;
+
+    graph::astar(&RiskMap(grid), (0, 0), |&p| p == goal, distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortest_path() {
+        let input = r#"
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+        "#;
+        let grid = Grid::parse_digits(&PuzzleInput::new("test", input)).unwrap();
+
+        let path = find_shortest_path(&grid).unwrap();
+        assert_eq!(40, path.cost);
+    }
+
+    #[test]
+    fn test_parse_rejects_zero_risk() {
+        let input = PuzzleInput::new("test", "11\n01\n");
+
+        assert_eq!(
+            "test:2:1: expected risk level 1-9, got '0'",
+            Chiton::parse(&input).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_shortest_path_full() {
+        let input = r#"
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+        "#;
+        let grid = Grid::parse_digits(&PuzzleInput::new("test", input)).unwrap();
+        let full_grid = get_full_grid(&grid);
+
+        let path = find_shortest_path(&full_grid).unwrap();
+        assert_eq!(315, path.cost);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{MyError, PuzzleInput};
+
+/// One line of an answers file: `<day> <part> <input> <answer>`.
+/// Multi-line answers are written on a single line with `\n` escapes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Reads every recorded answer, skipping blank lines and `#` comments.
+pub fn parse_answers(input: &PuzzleInput) -> Result<Vec<ExpectedAnswer>, MyError> {
+    input
+        .lines()
+        .filter(|l| {
+            let text = l.text.trim();
+            !text.is_empty() && !text.starts_with('#')
+        })
+        .map(|l| {
+            let (day, rest) = l.split_once(l.text.trim(), " ")?;
+            let (part, rest) = l.split_once(rest.trim_start(), " ")?;
+            let (path, answer) = l.split_once(rest.trim_start(), " ")?;
+            let part = match l.parse(part, "part")? {
+                p @ (1 | 2) => p,
+                _ => return Err(l.error(part, "part 1 or 2")),
+            };
+
+            Ok(ExpectedAnswer {
+                day: l.parse(day, "day")?,
+                part,
+                input: path.to_string(),
+                answer: answer.trim().replace("\\n", "\n"),
+            })
+        })
+        .collect()
+}
+
+/// Line-by-line comparison of two answers, marking expected lines with `-`
+/// and actual lines with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    let mut lines = vec![];
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => lines.push(format!("  {}", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    lines.push(format!("- {}", e));
+                }
+                if let Some(a) = a {
+                    lines.push(format!("+ {}", a));
+                }
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let input = PuzzleInput::new(
+            "answers.txt",
+            "# day part input answer\n\n1 2 day01/input.txt 1235\n13 2 day13/input.txt #.\\n.#\n",
+        );
+
+        let expected = vec![
+            ExpectedAnswer {
+                day: 1,
+                part: 2,
+                input: "day01/input.txt".to_string(),
+                answer: "1235".to_string(),
+            },
+            ExpectedAnswer {
+                day: 13,
+                part: 2,
+                input: "day13/input.txt".to_string(),
+                answer: "#.\n.#".to_string(),
+            },
+        ];
+        assert_eq!(expected, parse_answers(&input).unwrap());
+    }
+
+    #[test]
+    fn test_parse_answers_invalid_part() {
+        let input = PuzzleInput::new("answers.txt", "1 3 input.txt 1235");
+
+        let err = parse_answers(&input).unwrap_err();
+
+        assert_eq!(
+            "answers.txt:1:3: expected part 1 or 2, got '3'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!("- 40\n+ 41", diff("40", "41"));
+        assert_eq!("  ##\n- #.\n+ ..\n+ ##", diff("##\n#.", "##\n..\n##"));
+    }
+}
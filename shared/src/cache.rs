@@ -0,0 +1,230 @@
+use crate::{MyError, PuzzleInput};
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+
+pub const DEFAULT_YEAR: u16 = 2021;
+
+/// Puzzle inputs stored as `<dir>/<year>/<day>.txt`, optionally downloaded
+/// the first time they are needed.
+pub struct InputCache {
+    dir: PathBuf,
+    year: u16,
+    fetcher: Option<Fetcher>,
+}
+
+impl InputCache {
+    pub fn new<P: Into<PathBuf>>(dir: P, year: u16) -> Self {
+        InputCache {
+            dir: dir.into(),
+            year,
+            fetcher: None,
+        }
+    }
+
+    /// Reads `AOC_INPUT_DIR` and `AOC_YEAR`, falling back to `default_dir`
+    /// and `DEFAULT_YEAR`. Inputs are fetched only when both `AOC_URL` and
+    /// `AOC_SESSION` are set.
+    pub fn from_env<P: Into<PathBuf>>(default_dir: P) -> Result<Self, MyError> {
+        let dir = std::env::var("AOC_INPUT_DIR").map_or_else(|_| default_dir.into(), PathBuf::from);
+        let year = match std::env::var("AOC_YEAR") {
+            Ok(year) => year
+                .parse()
+                .map_err(|_| MyError::InvalidArgument(format!("Invalid AOC_YEAR '{}'", year)))?,
+            Err(_) => DEFAULT_YEAR,
+        };
+        let cache = InputCache::new(dir, year);
+
+        match (std::env::var("AOC_URL"), std::env::var("AOC_SESSION")) {
+            (Ok(url), Ok(session)) => Ok(cache.with_fetcher(Fetcher::new(&url, &session)?)),
+            _ => Ok(cache),
+        }
+    }
+
+    pub fn with_fetcher(mut self, fetcher: Fetcher) -> Self {
+        self.fetcher = Some(fetcher);
+        self
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir
+            .join(self.year.to_string())
+            .join(format!("{}.txt", day))
+    }
+
+    /// The cached input for `day`, downloading and storing it first if there
+    /// is a fetcher. `None` if it is neither cached nor fetchable.
+    pub fn find(&self, day: u8) -> Result<Option<PuzzleInput>, MyError> {
+        let path = self.path(day);
+        let name = path.to_string_lossy().into_owned();
+
+        if path.exists() {
+            return Ok(Some(PuzzleInput::from_file(&name)?));
+        }
+        match &self.fetcher {
+            Some(fetcher) => {
+                let text = fetcher.fetch(self.year, day)?;
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, &text)?;
+                Ok(Some(PuzzleInput { path: name, text }))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Downloads inputs from `<base_url>/<year>/day/<day>/input` using a session
+/// cookie. Only plain `http://` is supported, which is enough for a local
+/// mirror.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetcher {
+    host: String,
+    prefix: String,
+    session: String,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str) -> Result<Self, MyError> {
+        let rest = base_url.strip_prefix("http://").ok_or_else(|| {
+            MyError::InvalidArgument(format!(
+                "Only http:// URLs are supported, got '{}'",
+                base_url
+            ))
+        })?;
+        let (host, prefix) = match rest.find('/') {
+            Some(idx) => rest.split_at(idx),
+            None => (rest, ""),
+        };
+
+        Ok(Fetcher {
+            host: host.to_owned(),
+            prefix: prefix.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        })
+    }
+
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String, MyError> {
+        let address = if self.host.contains(':') {
+            self.host.clone()
+        } else {
+            format!("{}:80", self.host)
+        };
+        let path = format!("{}/{}/day/{}/input", self.prefix, year, day);
+
+        let mut stream = TcpStream::connect(&address)?;
+        write!(
+            stream,
+            "GET {} HTTP/1.0\r\nHost: {}\r\nCookie: session={}\r\nConnection: close\r\n\r\n",
+            path, self.host, self.session
+        )?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+
+        let fail = |reason: &str| MyError::Fetch(format!("GET {}{}: {}", self.host, path, reason));
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| fail("malformed response"))?;
+        let status = head.lines().next().unwrap_or_default();
+        match status.split_whitespace().nth(1) {
+            Some("200") => Ok(body.to_owned()),
+            _ => Err(fail(status)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::path::Path;
+    use std::thread;
+
+    /// Directory under the system temp dir that no other test uses.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn read_request(stream: &mut TcpStream) -> String {
+        let mut request = vec![];
+        let mut buf = [0; 512];
+        while !request.ends_with(b"\r\n\r\n") {
+            let n = stream.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+        String::from_utf8(request).unwrap()
+    }
+
+    /// Serves a single request with `response` and returns what was requested.
+    fn serve_once(response: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/mirror", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = read_request(&mut stream);
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_cache_path() {
+        let cache = InputCache::new("inputs", 2021);
+
+        assert_eq!(Path::new("inputs/2021/7.txt"), cache.path(7));
+    }
+
+    #[test]
+    fn test_find_cached() {
+        let dir = temp_dir("cached");
+        let cache = InputCache::new(&dir, 2021);
+        assert_eq!(None, cache.find(1).unwrap());
+
+        fs::create_dir_all(dir.join("2021")).unwrap();
+        fs::write(dir.join("2021/1.txt"), "199\n200\n").unwrap();
+
+        let input = cache.find(1).unwrap().unwrap();
+        assert_eq!("199\n200\n", input.text);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_find_fetches_and_stores() {
+        let (url, server) = serve_once("HTTP/1.1 200 OK\r\nContent-Length: 8\r\n\r\n199\n200\n");
+        let dir = temp_dir("fetch");
+        let cache = InputCache::new(&dir, 2021).with_fetcher(Fetcher::new(&url, "token").unwrap());
+
+        let input = cache.find(6).unwrap().unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /mirror/2021/day/6/input HTTP/1.0\r\n"));
+        assert!(request.contains("Cookie: session=token\r\n"));
+        assert_eq!("199\n200\n", input.text);
+        assert_eq!("199\n200\n", fs::read_to_string(cache.path(6)).unwrap());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error_status() {
+        let (url, server) = serve_once("HTTP/1.1 404 Not Found\r\n\r\nmissing");
+        let fetcher = Fetcher::new(&url, "token").unwrap();
+
+        let err = fetcher.fetch(2021, 26).unwrap_err();
+
+        server.join().unwrap();
+        assert!(err.to_string().ends_with("HTTP/1.1 404 Not Found"));
+    }
+
+    #[test]
+    fn test_fetcher_requires_http() {
+        assert!(Fetcher::new("https://adventofcode.com", "token").is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Anything that can list the nodes one step away from a given node.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// Every node reachable from `node` in one step, with the cost of that step.
+    fn neighbours(&self, node: &Self::Node) -> Vec<(Self::Node, u64)>;
+}
+
+/// Path found by a search, from the start node to the goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: u64,
+}
+
+pub fn dijkstra<G, F>(graph: &G, start: G::Node, is_goal: F) -> Option<Path<G::Node>>
+where
+    G: Graph,
+    F: Fn(&G::Node) -> bool,
+{
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// Cheapest path from `start` to the first node accepted by `is_goal`.
+/// `heuristic` estimates the remaining cost from a node and must never
+/// overestimate it, otherwise the path found may not be the cheapest.
+pub fn astar<G, F, H>(graph: &G, start: G::Node, is_goal: F, heuristic: H) -> Option<Path<G::Node>>
+where
+    G: Graph,
+    F: Fn(&G::Node) -> bool,
+    H: Fn(&G::Node) -> u64,
+{
+    let mut costs: HashMap<G::Node, u64> = HashMap::new();
+    let mut predecessors: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    costs.insert(start.clone(), 0);
+    queue.push(Candidate {
+        estimate: heuristic(&start),
+        cost: 0,
+        node: start,
+    });
+
+    while let Some(Candidate { cost, node, .. }) = queue.pop() {
+        if cost > costs[&node] {
+            // A cheaper way to this node was already expanded
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(Path {
+                nodes: reconstruct_path(&predecessors, node),
+                cost,
+            });
+        }
+
+        for (next, step_cost) in graph.neighbours(&node) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                predecessors.insert(next.clone(), node.clone());
+                queue.push(Candidate {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Path with the fewest steps from `start` to the first node accepted by
+/// `is_goal`, ignoring step costs. The returned cost is the number of steps.
+pub fn bfs<G, F>(graph: &G, start: G::Node, is_goal: F) -> Option<Path<G::Node>>
+where
+    G: Graph,
+    F: Fn(&G::Node) -> bool,
+{
+    let mut predecessors: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut visited: HashSet<G::Node> = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    visited.insert(start.clone());
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let nodes = reconstruct_path(&predecessors, node);
+            let cost = nodes.len() as u64 - 1;
+            return Some(Path { nodes, cost });
+        }
+
+        for (next, _) in graph.neighbours(&node) {
+            if visited.insert(next.clone()) {
+                predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Follows `predecessors` back from `end` to the node that has none, and
+/// returns the nodes visited in order from that node to `end`.
+pub fn reconstruct_path<N: Clone + Eq + Hash>(predecessors: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut nodes = vec![end];
+    while let Some(prev) = predecessors.get(nodes.last().unwrap()) {
+        nodes.push(prev.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+/// Queue entry ordered so that `BinaryHeap` pops the lowest estimate first.
+struct Candidate<N> {
+    estimate: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Candidate<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Candidate<N> {}
+
+impl<N> Ord for Candidate<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N> PartialOrd for Candidate<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Edges(Vec<(char, char, u64)>);
+
+    impl Graph for Edges {
+        type Node = char;
+
+        fn neighbours(&self, node: &char) -> Vec<(char, u64)> {
+            self.0
+                .iter()
+                .filter(|(from, _, _)| from == node)
+                .map(|&(_, to, cost)| (to, cost))
+                .collect()
+        }
+    }
+
+    fn example() -> Edges {
+        Edges(vec![
+            ('a', 'b', 1),
+            ('b', 'd', 10),
+            ('a', 'c', 2),
+            ('c', 'e', 2),
+            ('e', 'd', 2),
+            ('d', 'a', 1),
+        ])
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(&example(), 'a', |&n| n == 'd').unwrap();
+
+        assert_eq!(6, path.cost);
+        assert_eq!(vec!['a', 'c', 'e', 'd'], path.nodes);
+    }
+
+    #[test]
+    fn test_astar() {
+        let remaining = |&n: &char| match n {
+            'c' => 4,
+            'e' => 2,
+            _ => 0,
+        };
+
+        let path = astar(&example(), 'a', |&n| n == 'd', remaining).unwrap();
+
+        assert_eq!(6, path.cost);
+        assert_eq!(vec!['a', 'c', 'e', 'd'], path.nodes);
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs(&example(), 'a', |&n| n == 'd').unwrap();
+
+        assert_eq!(2, path.cost);
+        assert_eq!(vec!['a', 'b', 'd'], path.nodes);
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        assert_eq!(None, dijkstra(&example(), 'a', |&n| n == 'z'));
+        assert_eq!(None, bfs(&example(), 'a', |&n| n == 'z'));
+    }
+
+    #[test]
+    fn test_reconstruct_path() {
+        let predecessors: HashMap<_, _> = vec![(2, 1), (3, 2), (5, 3)].into_iter().collect();
+
+        assert_eq!(vec![1, 2, 3, 5], reconstruct_path(&predecessors, 5));
+        assert_eq!(vec![1], reconstruct_path(&predecessors, 1));
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{MyError, PuzzleInput};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Position in a grid as `(row, col)`.
+pub type Point = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Fails if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, MyError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if let Some(idx) = rows.iter().position(|row| row.len() != cols) {
+            return Err(MyError::InvalidArgument(format!(
+                "Grid row {} has {} cells, expected {}",
+                idx,
+                rows[idx].len(),
+                cols
+            )));
+        }
+
+        Ok(Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn from_fn<F: FnMut(Point) -> T>(rows: usize, cols: usize, mut f: F) -> Self {
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+        Grid { rows, cols, cells }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): Point) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[point.0 * self.cols + point.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.0 * self.cols + point.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// Every point in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (rows, cols) = (self.rows, self.cols);
+        (0..rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Points above, left, right and below `point` that lie inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    /// Like `neighbours4`, but including diagonals.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbours(point, &SURROUNDING)
+    }
+
+    fn neighbours(
+        &self,
+        (row, col): Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> {
+        let (rows, cols) = (self.rows, self.cols);
+        offsets.iter().filter_map(move |&(d_row, d_col)| {
+            let row = row.checked_add_signed(d_row)?;
+            let col = col.checked_add_signed(d_col)?;
+            (row < rows && col < cols).then_some((row, col))
+        })
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.cols, self.rows, |(row, col)| self[(col, row)].clone())
+    }
+}
+
+impl Grid<u8> {
+    /// Reads one row of single digits per non-blank line.
+    pub fn parse_digits(input: &PuzzleInput) -> Result<Self, MyError> {
+        let mut rows: Vec<Vec<u8>> = vec![];
+        for line in input.lines().filter(|l| !l.text.trim().is_empty()) {
+            let row = line
+                .digits()?
+                .into_iter()
+                .map(|d| d as u8)
+                .collect::<Vec<_>>();
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    let expected = format!("row of {} digits", first.len());
+                    return Err(line.error(line.text.trim(), &expected));
+                }
+            }
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("point {:?} out of bounds", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("point {:?} out of bounds", point))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u8> {
+        Grid::parse_digits(&PuzzleInput::new("test", "123\n456\n")).unwrap()
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = example();
+
+        assert_eq!((2, 3), (grid.rows(), grid.cols()));
+        assert_eq!(6, grid[(1, 2)]);
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(vec![&2, &5], grid.column(1).collect::<Vec<_>>());
+        assert_eq!("123\n456\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_digits_ragged() {
+        let input = PuzzleInput::new("test", "123\n45\n");
+
+        let err = Grid::parse_digits(&input).unwrap_err();
+
+        assert_eq!(
+            "test:2:1: expected row of 3 digits, got '45'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+
+        let corner = grid.neighbours4((0, 0)).collect::<Vec<_>>();
+        assert_eq!(vec![(0, 1), (1, 0)], corner);
+
+        let edge = grid.neighbours8((1, 1)).collect::<Vec<_>>();
+        assert_eq!(vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)], edge);
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = example();
+
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(None, grid.get((0, 3)));
+    }
+
+    #[test]
+    fn test_map_and_transpose() {
+        let grid = example().map(|&d| d as u32 * 10);
+
+        assert_eq!(60, grid[(1, 2)]);
+        assert_eq!(60, grid.transpose()[(2, 1)]);
+        assert_eq!((3, 2), (grid.transpose().rows(), grid.transpose().cols()));
+    }
+}
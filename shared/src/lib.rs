@@ -1,22 +1,119 @@
-use std::io::BufRead;
-use std::fs::File;
-use std::io::BufReader;
-use std::io::Lines;
+pub mod answers;
+mod cache;
+pub mod graph;
+mod grid;
+mod solution;
+mod source;
+mod timing;
+mod window;
+
+pub use cache::{Fetcher, InputCache, DEFAULT_YEAR};
+pub use grid::{Grid, Point};
+pub use solution::{print_results, run_main, solve, Answer, PartResult, PuzzleInput, Solution};
+pub use source::InputSource;
+pub use timing::{time_solution, Stage, Timing};
+pub use window::{Aggregate, Number, SlidingWindow};
+
 use std::fmt;
 use std::io;
+use std::str::FromStr;
+
+/// Arguments accepted by every day's own binary.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MainArgs {
+    pub input: InputSource,
+    pub format: Format,
+}
+
+/// Parses `<path|-> [--format text|json]` or `--input-str <text> [--format text|json]`.
+pub fn parse_main_args<I: Iterator<Item = String>>(mut args: I) -> Result<MainArgs, MyError> {
+    let mut input = None;
+    let mut format = Format::Text;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = option_value(&mut args, "--format")?.parse()?,
+            "--input-str" if input.is_none() => {
+                input = Some(InputSource::Str(option_value(&mut args, "--input-str")?))
+            }
+            _ if input.is_none() => input = Some(InputSource::from_arg(&arg)),
+            other => {
+                return Err(MyError::InvalidArgument(format!(
+                    "Unexpected argument '{}'",
+                    other
+                )))
+            }
+        }
+    }
+
+    Ok(MainArgs {
+        input: input.ok_or(MyError::InputNotProvided)?,
+        format,
+    })
+}
 
-pub fn read_first_arg() -> Result<String, MyError> {
-    let args: Vec<String> = std::env::args().skip(1).collect();
-    if args.is_empty() {
-        return Err(MyError::InputNotProvided);
+/// The value following `option` on the command line.
+pub fn option_value<I: Iterator<Item = String>>(
+    args: &mut I,
+    option: &str,
+) -> Result<String, MyError> {
+    args.next()
+        .ok_or_else(|| MyError::InvalidArgument(format!("Option {} requires a value", option)))
+}
+
+/// How the runner writes its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(MyError::InvalidArgument(format!(
+                "Unknown format '{}', expected text or json",
+                other
+            ))),
+        }
     }
-    Ok(args[0].to_owned())
 }
 
-#[derive(Debug)]
 pub enum MyError {
     InputNotProvided,
+    InvalidArgument(String),
     FileNotFound(io::Error),
+    Parse(ParseError),
+    NoAnswer(String),
+    NotSolved { day: u8, part: u8 },
+    VerifyFailed { failed: usize, total: usize },
+    Fetch(String),
+}
+
+/// Location and description of a piece of input that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub path: String,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl MyError {
+    pub fn parse(path: &str, line: usize, column: usize, expected: &str, found: &str) -> Self {
+        MyError::Parse(ParseError {
+            path: path.to_owned(),
+            line,
+            column,
+            expected: expected.to_owned(),
+            found: found.to_owned(),
+        })
+    }
 }
 
 impl From<io::Error> for MyError {
@@ -25,19 +122,226 @@ impl From<io::Error> for MyError {
     }
 }
 
+impl From<ParseError> for MyError {
+    fn from(error: ParseError) -> MyError {
+        MyError::Parse(error)
+    }
+}
+
 impl fmt::Display for MyError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            MyError::InputNotProvided => write!(f, "Input file must be provided"),
+            MyError::InputNotProvided => {
+                write!(f, "Input must be provided as a path, '-' or --input-str")
+            }
+            MyError::InvalidArgument(message) => write!(f, "{}", message),
             MyError::FileNotFound(inner) => write!(f, "{}", inner),
+            MyError::Parse(inner) => write!(f, "{}", inner),
+            MyError::NoAnswer(reason) => write!(f, "No answer: {}", reason),
+            MyError::NotSolved { day, part } => {
+                write!(f, "Day {} part {} is not solved", day, part)
+            }
+            MyError::VerifyFailed { failed, total } => {
+                write!(f, "{} of {} answers did not match", failed, total)
+            }
+            MyError::Fetch(reason) => write!(f, "Failed to fetch input: {}", reason),
         }
     }
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: expected {}, got '{}'",
+            self.path, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+// `main` reports errors through `Debug`, so keep it as readable as `Display`.
+impl fmt::Debug for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
 impl std::error::Error for MyError {}
 
-pub fn file_lines(file_path: &str) -> Result<Lines<BufReader<File>>, MyError> {
-    let f = File::open(file_path)?;
-    let f = BufReader::new(f);
-    Ok(f.lines())
-}
\ No newline at end of file
+/// Splits `input` into lines that remember where they came from, so that
+/// parse failures can point at the offending text.
+pub fn input_lines<'a>(path: &'a str, input: &'a str) -> impl Iterator<Item = InputLine<'a>> {
+    input
+        .lines()
+        .enumerate()
+        .map(move |(idx, text)| InputLine::new(path, idx + 1, text))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputLine<'a> {
+    pub path: &'a str,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn new(path: &'a str, number: usize, text: &'a str) -> Self {
+        InputLine { path, number, text }
+    }
+
+    /// Error pointing at `field`, which should be a slice of this line's text.
+    /// Fields that don't belong to the line are reported at column 1.
+    pub fn error(&self, field: &str, expected: &str) -> MyError {
+        MyError::parse(
+            self.path,
+            self.number,
+            self.column_of(field),
+            expected,
+            field,
+        )
+    }
+
+    pub fn parse<T: FromStr>(&self, field: &'a str, expected: &str) -> Result<T, MyError> {
+        field.parse().map_err(|_| self.error(field, expected))
+    }
+
+    pub fn split_once(
+        &self,
+        field: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), MyError> {
+        field
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(field, &format!("'{}'", delimiter)))
+    }
+
+    /// Parses every `separator`-delimited item of `field`.
+    pub fn parse_list<T: FromStr>(
+        &self,
+        field: &'a str,
+        separator: &str,
+        expected: &str,
+    ) -> Result<Vec<T>, MyError> {
+        field
+            .split(separator)
+            .map(|item| self.parse(item.trim(), expected))
+            .collect()
+    }
+
+    pub fn digits(&self) -> Result<Vec<u32>, MyError> {
+        let text = self.text.trim();
+        text.char_indices()
+            .map(|(idx, ch)| {
+                ch.to_digit(10)
+                    .ok_or_else(|| self.error(&text[idx..idx + ch.len_utf8()], "digit"))
+            })
+            .collect()
+    }
+
+    pub fn column_of(&self, field: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (field.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+}
+
+/// Error for input that ends before `expected` was found.
+pub fn unexpected_end(path: &str, input: &str, expected: &str) -> MyError {
+    MyError::parse(path, input.lines().count() + 1, 1, expected, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_points_at_field() {
+        let line = InputLine::new("input.txt", 37, "forward forwrd");
+        let (_, value) = line.split_once(line.text, " ").unwrap();
+
+        let err = line.parse::<i32>(value, "integer").unwrap_err();
+
+        assert_eq!(
+            "input.txt:37:9: expected integer, got 'forwrd'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_split_once_missing_delimiter() {
+        let line = InputLine::new("input.txt", 2, "0,9 5,9");
+
+        let err = line.split_once(line.text, " -> ").unwrap_err();
+
+        assert_eq!(
+            "input.txt:2:1: expected ' -> ', got '0,9 5,9'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let line = InputLine::new("input.txt", 1, "7,4,x,5");
+
+        let err = line
+            .parse_list::<i32>(line.text, ",", "integer")
+            .unwrap_err();
+        assert_eq!("input.txt:1:5: expected integer, got 'x'", err.to_string());
+
+        let line = InputLine::new("input.txt", 1, "7,4,9");
+        assert_eq!(
+            vec![7, 4, 9],
+            line.parse_list::<i32>(line.text, ",", "integer").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_digits() {
+        let line = InputLine::new("input.txt", 4, "21999x3210");
+
+        let err = line.digits().unwrap_err();
+        assert_eq!("input.txt:4:6: expected digit, got 'x'", err.to_string());
+    }
+
+    #[test]
+    fn test_parse_main_args() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        let expected = MainArgs {
+            input: InputSource::File("input.txt".to_string()),
+            format: Format::Json,
+        };
+        let actual = parse_main_args(args("--format json input.txt").into_iter()).unwrap();
+        assert_eq!(expected, actual);
+
+        let expected = MainArgs {
+            input: InputSource::Stdin,
+            format: Format::Text,
+        };
+        assert_eq!(expected, parse_main_args(args("-").into_iter()).unwrap());
+
+        let expected = MainArgs {
+            input: InputSource::Str("199".to_string()),
+            format: Format::Text,
+        };
+        let actual = parse_main_args(args("--input-str 199").into_iter()).unwrap();
+        assert_eq!(expected, actual);
+
+        assert!(parse_main_args(args("--format json").into_iter()).is_err());
+        assert!(parse_main_args(args("input.txt --input-str 199").into_iter()).is_err());
+        assert!(parse_main_args(args("input.txt --format yaml").into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_input_lines_numbering() {
+        let lines = input_lines("example", "1\n2\n\n4").collect::<Vec<_>>();
+
+        assert_eq!(4, lines.len());
+        assert_eq!(3, lines[2].number);
+        assert_eq!("4", lines[3].text);
+    }
+}
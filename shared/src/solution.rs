@@ -0,0 +1,203 @@
+use crate::{input_lines, parse_main_args, Format, InputLine, MyError};
+use serde_json::{json, Value};
+use std::fmt::Display;
+
+/// Puzzle text together with the name it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleInput {
+    pub path: String,
+    pub text: String,
+}
+
+impl PuzzleInput {
+    pub fn new(path: &str, text: &str) -> Self {
+        PuzzleInput {
+            path: path.to_owned(),
+            text: text.to_owned(),
+        }
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, MyError> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(PuzzleInput {
+            path: path.to_owned(),
+            text,
+        })
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = InputLine<'_>> {
+        input_lines(&self.path, &self.text)
+    }
+
+    pub fn unexpected_end(&self, expected: &str) -> MyError {
+        crate::unexpected_end(&self.path, &self.text, expected)
+    }
+}
+
+/// A part's answer, along with any intermediate values worth reporting.
+pub trait Answer: Display {
+    fn details(&self) -> Option<Value> {
+        None
+    }
+}
+
+impl Answer for i32 {}
+impl Answer for u32 {}
+impl Answer for i64 {}
+impl Answer for u64 {}
+impl Answer for usize {}
+impl Answer for String {}
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, MyError>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, MyError>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, MyError>;
+}
+
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Result<String, MyError>,
+    pub details: Option<Value>,
+}
+
+impl PartResult {
+    pub fn new<A: Answer>(day: u8, part: u8, answer: Result<A, MyError>) -> Self {
+        let details = answer.as_ref().ok().and_then(Answer::details);
+        PartResult {
+            day,
+            part,
+            answer: answer.map(|answer| answer.to_string()),
+            details,
+        }
+    }
+
+    /// Parts that failed carry their error in place of the answer.
+    pub fn to_json(&self) -> Value {
+        let mut json = json!({ "day": self.day, "part": self.part });
+        match &self.answer {
+            Ok(answer) => json["answer"] = json!(answer),
+            Err(err) => json["error"] = json!(err.to_string()),
+        }
+        if let Some(details) = &self.details {
+            json["details"] = details.clone();
+        }
+        json
+    }
+}
+
+/// Parses the input once and runs each of the requested parts on it.
+pub fn solve<S: Solution>(input: &PuzzleInput, parts: &[u8]) -> Result<Vec<PartResult>, MyError> {
+    let parsed = S::parse(input)?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| match part {
+            1 => PartResult::new(S::DAY, part, S::part1(&parsed)),
+            2 => PartResult::new(S::DAY, part, S::part2(&parsed)),
+            _ => PartResult::new::<String>(
+                S::DAY,
+                part,
+                Err(MyError::InvalidArgument(format!(
+                    "Part must be 1 or 2, got {}",
+                    part
+                ))),
+            ),
+        })
+        .collect())
+}
+
+/// Prints every answer, skipping over parts that are not solved yet. A part
+/// that fails doesn't stop the others; the first error is returned once
+/// everything else has been printed.
+pub fn print_results(results: Vec<PartResult>, format: Format) -> Result<(), MyError> {
+    let mut json = vec![];
+    let mut first_err = None;
+    for result in results {
+        let value = result.to_json();
+        match result.answer {
+            Ok(answer) => match format {
+                Format::Text if answer.contains('\n') => {
+                    println!("Part {}:\n{}", result.part, answer)
+                }
+                Format::Text => println!("Part {}: {}", result.part, answer),
+                Format::Json => json.push(value),
+            },
+            Err(err @ MyError::NotSolved { .. }) => eprintln!("{}", err),
+            Err(err) => {
+                json.push(value);
+                first_err.get_or_insert(err);
+            }
+        }
+    }
+
+    if format == Format::Json {
+        println!("{}", Value::Array(json));
+    }
+    first_err.map_or(Ok(()), Err)
+}
+
+/// Entry point for a day's own binary: solves both parts of the input named
+/// on the command line.
+pub fn run_main<S: Solution>() -> Result<(), MyError> {
+    let args = parse_main_args(std::env::args().skip(1))?;
+    let input = args.input.read()?;
+    print_results(solve::<S>(&input, &[1, 2])?, args.format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Rates(i32, i32);
+
+    impl Display for Rates {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0 * self.1)
+        }
+    }
+
+    impl Answer for Rates {
+        fn details(&self) -> Option<Value> {
+            Some(json!({ "gamma_rate": self.0, "epsilon_rate": self.1 }))
+        }
+    }
+
+    #[test]
+    fn test_part_result_to_json() {
+        let result = PartResult::new(3, 1, Ok(Rates(22, 9)));
+        let expected = json!({
+            "day": 3,
+            "part": 1,
+            "answer": "198",
+            "details": { "gamma_rate": 22, "epsilon_rate": 9 },
+        });
+        assert_eq!(expected, result.to_json());
+
+        let result = PartResult::new(1, 2, Ok(5_usize));
+        let expected = json!({ "day": 1, "part": 2, "answer": "5" });
+        assert_eq!(expected, result.to_json());
+
+        let err = MyError::NoAnswer("no dots on the paper".to_owned());
+        let result = PartResult::new::<usize>(13, 2, Err(err));
+        let expected = json!({ "day": 13, "part": 2, "error": "No answer: no dots on the paper" });
+        assert_eq!(expected, result.to_json());
+    }
+
+    #[test]
+    fn test_print_results_returns_first_error() {
+        let results = vec![
+            PartResult::new(10, 1, Ok(26_397_usize)),
+            PartResult::new::<usize>(10, 2, Err(MyError::NoAnswer("first".to_owned()))),
+            PartResult::new::<usize>(10, 2, Err(MyError::NoAnswer("second".to_owned()))),
+        ];
+        let err = print_results(results, Format::Json).unwrap_err();
+        assert_eq!("No answer: first", err.to_string());
+    }
+}
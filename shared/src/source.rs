@@ -0,0 +1,80 @@
+use crate::{MyError, PuzzleInput};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+
+/// Where puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(String),
+    Stdin,
+    Str(String),
+}
+
+impl InputSource {
+    /// `-` stands for stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.to_owned()),
+        }
+    }
+
+    /// Name used when reporting parse errors.
+    pub fn name(&self) -> &str {
+        match self {
+            InputSource::File(path) => path,
+            InputSource::Stdin => "<stdin>",
+            InputSource::Str(_) => "<input-str>",
+        }
+    }
+
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, MyError> {
+        Ok(match self {
+            InputSource::File(path) => Box::new(BufReader::new(File::open(path)?)),
+            InputSource::Stdin => Box::new(BufReader::new(io::stdin())),
+            InputSource::Str(text) => Box::new(Cursor::new(text.clone().into_bytes())),
+        })
+    }
+
+    /// Reads the whole input at once.
+    pub fn read(&self) -> Result<PuzzleInput, MyError> {
+        let mut text = String::new();
+        self.reader()?.read_to_string(&mut text)?;
+        Ok(PuzzleInput {
+            path: self.name().to_owned(),
+            text,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File("input.txt".to_string()),
+            InputSource::from_arg("input.txt")
+        );
+    }
+
+    #[test]
+    fn test_read_str() {
+        let source = InputSource::Str("199\n200\n".to_string());
+
+        let input = source.read().unwrap();
+        assert_eq!(PuzzleInput::new("<input-str>", "199\n200\n"), input);
+
+        let lines = source.reader().unwrap().lines().count();
+        assert_eq!(2, lines);
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = InputSource::File("does-not-exist.txt".to_string());
+
+        assert!(matches!(source.read(), Err(MyError::FileNotFound(_))));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{MyError, PuzzleInput, Solution};
+use serde_json::{json, Value};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+/// Every measured duration of a single stage of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    pub samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn new(day: u8, stage: Stage) -> Self {
+        Timing {
+            day,
+            stage,
+            samples: vec![],
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        match sorted.len() {
+            0 => Duration::default(),
+            len if len % 2 == 0 => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+            len => sorted[len / 2],
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "day": self.day,
+            "stage": self.stage.to_string(),
+            "runs": self.samples.len(),
+            "min_ns": self.min().as_nanos() as u64,
+            "median_ns": self.median().as_nanos() as u64,
+            "max_ns": self.max().as_nanos() as u64,
+        })
+    }
+}
+
+/// Times parsing and each of the requested parts separately, `runs` times
+/// over. Parts that are not solved yet are left out of the result.
+pub fn time_solution<S: Solution>(
+    input: &PuzzleInput,
+    parts: &[u8],
+    runs: usize,
+) -> Result<Vec<Timing>, MyError> {
+    let mut parse = Timing::new(S::DAY, Stage::Parse);
+    let mut part_timings = parts
+        .iter()
+        .map(|&part| Timing::new(S::DAY, Stage::Part(part)))
+        .collect::<Vec<_>>();
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        parse.samples.push(start.elapsed());
+
+        for timing in &mut part_timings {
+            let start = Instant::now();
+            let result = match timing.stage {
+                Stage::Part(1) => S::part1(&parsed).map(|answer| drop(black_box(answer))),
+                Stage::Part(2) => S::part2(&parsed).map(|answer| drop(black_box(answer))),
+                stage => Err(MyError::InvalidArgument(format!(
+                    "Cannot time stage {}",
+                    stage
+                ))),
+            };
+            let elapsed = start.elapsed();
+
+            match result {
+                Ok(()) => timing.samples.push(elapsed),
+                Err(MyError::NotSolved { .. }) => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    Ok(std::iter::once(parse)
+        .chain(part_timings.into_iter().filter(|t| !t.samples.is_empty()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(samples: &[u64]) -> Timing {
+        Timing {
+            day: 1,
+            stage: Stage::Part(1),
+            samples: samples
+                .iter()
+                .map(|&ms| Duration::from_millis(ms))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_timing_statistics() {
+        let odd = timing(&[5, 1, 3]);
+        assert_eq!(Duration::from_millis(1), odd.min());
+        assert_eq!(Duration::from_millis(3), odd.median());
+        assert_eq!(Duration::from_millis(5), odd.max());
+
+        let even = timing(&[4, 1, 2, 8]);
+        assert_eq!(Duration::from_millis(3), even.median());
+    }
+
+    #[test]
+    fn test_timing_to_json() {
+        let expected = json!({
+            "day": 1,
+            "stage": "part1",
+            "runs": 2,
+            "min_ns": 1_000_000,
+            "median_ns": 2_000_000,
+            "max_ns": 3_000_000,
+        });
+        assert_eq!(expected, timing(&[3, 1]).to_json());
+    }
+}
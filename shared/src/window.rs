@@ -0,0 +1,232 @@
+use crate::MyError;
+use std::collections::VecDeque;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+/// Numbers a `SlidingWindow` can aggregate.
+pub trait Number: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    /// Whether subtracting a value from a sum it was added to gives back the
+    /// sum without it, which rounding breaks for floats.
+    const EXACT: bool;
+
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_number {
+    ($zero:expr, $exact:expr => $($t:ty),*) => {
+        $(impl Number for $t {
+            const ZERO: Self = $zero;
+            const EXACT: bool = $exact;
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_number!(0, true => i32, i64, u32, u64, usize);
+impl_number!(0.0, false => f32, f64);
+
+/// The last `size` values pushed, with their sum, minimum and maximum kept up
+/// to date in amortised O(1) per push. Float sums are added up again over the
+/// window when a value drops out, so they cost O(size) per push.
+#[derive(Debug, Clone)]
+pub struct SlidingWindow<T> {
+    size: usize,
+    values: VecDeque<T>,
+    sum: T,
+    pushed: usize,
+    // Candidates for the minimum (maximum) as (push index, value), strictly
+    // increasing (decreasing) from front to back.
+    mins: VecDeque<(usize, T)>,
+    maxs: VecDeque<(usize, T)>,
+}
+
+impl<T: Number> SlidingWindow<T> {
+    /// Panics if `size` is zero.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "window size must be positive");
+        SlidingWindow {
+            size,
+            values: VecDeque::with_capacity(size),
+            sum: T::ZERO,
+            pushed: 0,
+            mins: VecDeque::new(),
+            maxs: VecDeque::new(),
+        }
+    }
+
+    /// Adds `value`, returning the value that dropped out of a full window.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        let evicted = if self.is_full() {
+            self.values.pop_front()
+        } else {
+            None
+        };
+        self.values.push_back(value);
+        self.sum = match evicted {
+            Some(oldest) if T::EXACT => self.sum - oldest + value,
+            Some(_) => self.values.iter().fold(T::ZERO, |sum, &value| sum + value),
+            None => self.sum + value,
+        };
+
+        let idx = self.pushed;
+        self.pushed += 1;
+        while self.mins.back().is_some_and(|&(_, min)| min >= value) {
+            self.mins.pop_back();
+        }
+        self.mins.push_back((idx, value));
+        while self.maxs.back().is_some_and(|&(_, max)| max <= value) {
+            self.maxs.pop_back();
+        }
+        self.maxs.push_back((idx, value));
+
+        let first = self.pushed - self.values.len();
+        while self.mins.front().is_some_and(|&(idx, _)| idx < first) {
+            self.mins.pop_front();
+        }
+        while self.maxs.front().is_some_and(|&(idx, _)| idx < first) {
+            self.maxs.pop_front();
+        }
+        evicted
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.values.len() == self.size
+    }
+
+    /// Values from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+
+    pub fn sum(&self) -> T {
+        self.sum
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.mins.front().map(|&(_, min)| min)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.maxs.front().map(|&(_, max)| max)
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.sum.to_f64() / self.len() as f64)
+        }
+    }
+
+    pub fn aggregate(&self, aggregate: Aggregate) -> Option<f64> {
+        match aggregate {
+            Aggregate::Sum => Some(self.sum().to_f64()),
+            Aggregate::Min => self.min().map(Number::to_f64),
+            Aggregate::Max => self.max().map(Number::to_f64),
+            Aggregate::Mean => self.mean(),
+        }
+    }
+}
+
+/// Aggregate of a `SlidingWindow` chosen at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Sum,
+    Min,
+    Max,
+    Mean,
+}
+
+impl FromStr for Aggregate {
+    type Err = MyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sum" => Ok(Aggregate::Sum),
+            "min" => Ok(Aggregate::Min),
+            "max" => Ok(Aggregate::Max),
+            "mean" => Ok(Aggregate::Mean),
+            other => Err(MyError::InvalidArgument(format!(
+                "Unknown aggregate '{}', expected sum, min, max or mean",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPTHS: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_sum_and_eviction() {
+        let mut window = SlidingWindow::new(3);
+
+        assert_eq!(None, window.push(199));
+        assert_eq!(None, window.push(200));
+        assert!(!window.is_full());
+        assert_eq!(None, window.push(208));
+        assert!(window.is_full());
+        assert_eq!(607, window.sum());
+        assert_eq!(Some(199), window.push(210));
+        assert_eq!(618, window.sum());
+        assert_eq!(vec![&200, &208, &210], window.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_min_max_match_brute_force() {
+        for size in 1..=4 {
+            let mut window = SlidingWindow::new(size);
+            for (idx, &depth) in DEPTHS.iter().enumerate() {
+                window.push(depth);
+                let expected = &DEPTHS[(idx + 1).saturating_sub(size)..=idx];
+
+                assert_eq!(expected.iter().min().copied(), window.min());
+                assert_eq!(expected.iter().max().copied(), window.max());
+            }
+        }
+    }
+
+    #[test]
+    fn test_mean_and_aggregate() {
+        let mut window: SlidingWindow<f64> = SlidingWindow::new(2);
+        assert_eq!(None, window.mean());
+        assert_eq!(Some(0.0), window.aggregate(Aggregate::Sum));
+
+        window.push(1.5);
+        window.push(2.5);
+        window.push(4.5);
+
+        assert_eq!(Some(3.5), window.mean());
+        assert_eq!(Some(2.5), window.aggregate(Aggregate::Min));
+        assert_eq!(Some(4.5), window.aggregate("max".parse().unwrap()));
+        assert!("median".parse::<Aggregate>().is_err());
+    }
+
+    #[test]
+    fn test_float_sum_after_eviction() {
+        let mut window = SlidingWindow::new(2);
+        for value in [1e16, 1.0, 1.0] {
+            window.push(value);
+        }
+        assert_eq!(2.0, window.sum());
+    }
+}